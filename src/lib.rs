@@ -1,3 +1,4 @@
+#![no_std]
 #![deny(warnings)]
 #![forbid(unsafe_code)]
 #![warn(missing_docs)]
@@ -14,9 +15,281 @@
 //!
 //! assert_eq!(8, *MY_LIST.pop().0.unwrap());
 //! ```
+//!
+//! Methods which accept a function pointer, like [`ConstList::contains_by`], cannot yet be
+//! `const`, because calling function pointers from a `const fn` is not stable. They take
+//! function pointers rather than closures so that they may become `const` once it is.
+//! Likewise, methods which rely upon traits like [`ConstAdd`] cannot yet be `const`,
+//! since trait methods may not be called from a `const fn` on stable.
+//!
+//! Each list borrows the list that it was pushed onto, so a list may only be stored in a struct
+//! alongside the lifetime of those borrows. Lists defined as `const` items have no such
+//! restriction, since their nodes are promoted to `'static`:
+//!
+//! ```rust
+//! # use const_list::*;
+//! struct Config {
+//!     entries: &'static ConstList<'static, i32>,
+//! }
+//!
+//! const ENTRIES: ConstList<'static, i32> = const_list![2, 4, 8];
+//! const CONFIG: Config = Config { entries: &ENTRIES };
+//!
+//! assert_eq!(Some(&4), CONFIG.entries.get(1));
+//! ```
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt::{Debug, Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::iter::{Cloned, Copied, Enumerate, FusedIterator, Rev, Zip};
+use core::ops::Index;
+
+/// Creates a [`ConstList`] containing the provided items, such that
+/// iterating over the list yields them in the order they were written.
+///
+/// The macro expands to a chain of [`ConstList::push`] calls, with the last item pushed first.
+/// Each call borrows the list produced by the previous one, so every intermediate list must
+/// outlive the result. In `const` and `static` initializers the intermediate lists are
+/// promoted to `'static`, so the macro may be used freely there:
+///
+/// ```rust
+/// # use const_list::*;
+/// const MY_LIST: ConstList<'static, i32> = const_list![2, 4, 8];
+/// assert_eq!(Some(&2), MY_LIST.get(0));
+/// assert_eq!(Some(&8), MY_LIST.get(2));
+///
+/// const EMPTY: ConstList<'static, i32> = const_list![];
+/// assert!(EMPTY.is_empty());
+/// ```
+///
+/// Elsewhere, the intermediate lists are temporaries which are dropped at the end of the
+/// enclosing statement, so runtime lists must instead be built from separately-bound `push` calls.
+///
+/// The items are reversed through one level of macro recursion per item, so under the default
+/// `recursion_limit` of 128 the macro accepts at most 126 items. Longer lists require raising the
+/// limit in the invoking crate, for example with `#![recursion_limit = "256"]`.
+#[macro_export]
+macro_rules! const_list {
+    (@reverse ($base:expr) [] [$($reversed:expr),*]) => {
+        $base$(.push($reversed))*
+    };
+    (@reverse ($base:expr) [$first:expr $(, $rest:expr)*] [$($reversed:expr),*]) => {
+        $crate::const_list!(@reverse ($base) [$($rest),*] [$first $(, $reversed)*])
+    };
+    () => {
+        $crate::ConstList::new()
+    };
+    ($($item:expr),+ $(,)?) => {
+        $crate::const_list!(@reverse ($crate::ConstList::new()) [$($item),+] [])
+    };
+}
+
+/// Pushes the items of an array literal onto the beginning of a [`ConstList`], such that
+/// iterating over the resulting list yields the array's items in the order they were
+/// written, followed by the items of the base list.
+///
+/// Like [`const_list!`], the macro expands to a chain of [`ConstList::push`] calls, so the
+/// same restrictions upon the lifetimes of intermediate lists apply. The items must be
+/// written as an array literal, so that the macro can unroll them:
+///
+/// ```rust
+/// # use const_list::*;
+/// const BASE: ConstList<'static, i32> = const_list![8, 16];
+/// const MY_LIST: ConstList<'static, i32> = push_all!(BASE, [2, 4]);
+/// assert_eq!(MY_LIST, [2, 4, 8, 16]);
+/// ```
+#[macro_export]
+macro_rules! push_all {
+    ($base:expr, []) => {
+        $base
+    };
+    ($base:expr, [$($item:expr),+ $(,)?]) => {
+        $crate::const_list!(@reverse ($base) [$($item),+] [])
+    };
+}
+
+/// Checks that `$len` items fit within the fixed depth to which [`push_array!`], [`const_list_from!`]
+/// and [`clone_list!`] unroll their push chains, then invokes `$crate::$macro!` with `$args`
+/// followed by a bracketed list of every index below that depth. Those macros cannot see the
+/// length of their input, so this is the only place the limit is defined. With a leading `const`,
+/// the check happens at compile time.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __unroll {
+    (@indices $macro:ident!($($args:tt)*)) => {
+        $crate::$macro!($($args)* [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31])
+    };
+    (const $len:expr, $name:literal, $macro:ident!($($args:tt)*)) => {{
+        const { assert!($len <= 32, concat!($name, " supports at most 32 items")) };
+        $crate::__unroll!(@indices $macro!($($args)*))
+    }};
+    ($len:expr, $name:literal, $macro:ident!($($args:tt)*)) => {
+        assert!($len <= 32, concat!($name, " supports at most 32 items"));
+        $crate::__unroll!(@indices $macro!($($args)*));
+    };
+}
+
+/// Pushes each item of an array onto the beginning of a [`ConstList`], in array order.
+/// Since each item becomes the new head of the list, iterating over the resulting list
+/// yields the array's items in reverse order, followed by the items of the base list.
+///
+/// Unlike [`push_all!`], the array may be any `const` expression, such as a path to a
+/// `const` item, rather than a literal. Since its length is not visible to the macro,
+/// the push chain is unrolled to a fixed depth, so the array may hold at most 32 items.
+/// The items must be `Copy`, since each one is copied out of the array as it is pushed.
+/// The same restrictions upon the lifetimes of intermediate lists apply as for [`const_list!`].
+///
+/// ```rust
+/// # use const_list::*;
+/// const DATA: [i32; 4] = [1, 2, 3, 4];
+/// const MY_LIST: ConstList<'static, i32> = push_array!(ConstList::new(), DATA);
+/// assert_eq!(MY_LIST, [4, 3, 2, 1]);
+/// ```
+#[macro_export]
+macro_rules! push_array {
+    (@unroll ($list:expr) ($items:expr) []) => {
+        $list
+    };
+    (@unroll ($list:expr) ($items:expr) [$index:literal $(, $rest:literal)*]) => {
+        $crate::push_array!(@unroll ($list.push_index(&$items, $index)) ($items) [$($rest),*])
+    };
+    ($base:expr, $items:expr $(,)?) => {
+        $crate::__unroll!(const $items.len(), "push_array!", push_array!(@unroll ($base) ($items)))
+    };
+}
+
+/// Creates a [`ConstList`] from a `const` array expression, such as a path to a `const` item,
+/// with the same ordering as the array. The items must be `Copy`, and the array is subject to
+/// the same length limit as for [`push_array!`].
+///
+/// The macro expands to a chain of nested pushes onto an empty list, starting from the last
+/// item of the array. Each push borrows the node produced by the previous one, and in a `const`
+/// or `static` initializer all of these intermediate nodes are promoted to `'static`, so the
+/// resulting list may be stored directly. Elsewhere, the intermediate nodes are temporaries,
+/// and the same restrictions upon their lifetimes apply as for [`const_list!`].
+///
+/// ```rust
+/// # use const_list::*;
+/// const EMPTY: [i32; 0] = [];
+/// const DATA: [i32; 4] = [1, 2, 3, 4];
+///
+/// const EMPTY_LIST: ConstList<'static, i32> = const_list_from!(EMPTY);
+/// const MY_LIST: ConstList<'static, i32> = const_list_from!(DATA);
+///
+/// const _: () = assert!(EMPTY_LIST.is_empty());
+/// const _: () = assert!(*MY_LIST.get(0).unwrap() == DATA[0]);
+/// const _: () = assert!(*MY_LIST.get(3).unwrap() == DATA[3]);
+/// assert_eq!(MY_LIST, DATA);
+/// ```
+#[macro_export]
+macro_rules! const_list_from {
+    (@unroll ($list:expr) ($items:expr) []) => {
+        $list
+    };
+    (@unroll ($list:expr) ($items:expr) [$index:literal $(, $rest:literal)*]) => {
+        $crate::const_list_from!(@unroll ($list.push_index(&$items, $items.len().wrapping_sub($index + 1))) ($items) [$($rest),*])
+    };
+    ($items:expr $(,)?) => {
+        $crate::__unroll!(const $items.len(), "const_list_from!", const_list_from!(@unroll ($crate::ConstList::new()) ($items)))
+    };
+}
+
+/// Binds a copy of a [`ConstList`] to a new variable in the current scope, by rebuilding each
+/// of its nodes. The nodes of the copy are bound to hidden variables in the current scope,
+/// so the copy lives as long as that scope, regardless of the lifetime of the source list.
+///
+/// The items must be `Copy`, and the source list is subject to the same length limit as for
+/// [`push_array!`], which is checked at runtime.
+///
+/// ```rust
+/// # use const_list::*;
+/// const SOURCE: ConstList<'static, i32> = const_list![2, 4, 8];
+/// clone_list!(&SOURCE, copy);
+/// assert_eq!(copy, SOURCE);
+/// ```
+#[macro_export]
+macro_rules! clone_list {
+    (@unroll $source:ident, $previous:ident, $binding:ident, []) => {
+        let $binding = $previous;
+    };
+    (@unroll $source:ident, $previous:ident, $binding:ident, [$index:literal $(, $rest:literal)*]) => {
+        let node = $previous.push_from_back($source, $index);
+        $crate::clone_list!(@unroll $source, node, $binding, [$($rest),*]);
+    };
+    ($source:expr, $binding:ident) => {
+        let source: &$crate::ConstList<_> = $source;
+        let node = $crate::ConstList::new();
+        $crate::__unroll!(source.len(), "clone_list!", clone_list!(@unroll source, node, $binding,));
+    };
+}
+
+/// A numeric type whose values may be summed by [`ConstList::sum`].
+pub trait ConstAdd: Copy {
+    /// The additive identity.
+    const ZERO: Self;
+
+    /// Adds two values together.
+    fn const_add(self, other: Self) -> Self;
+}
+
+/// Implements [`ConstAdd`] for primitive numeric types.
+macro_rules! impl_const_add {
+    ($($ty:ty => $zero:expr),*) => {
+        $(
+            impl ConstAdd for $ty {
+                const ZERO: Self = $zero;
+
+                #[inline(always)]
+                fn const_add(self, other: Self) -> Self {
+                    self + other
+                }
+            }
+        )*
+    };
+}
+
+impl_const_add!(
+    u8 => 0, u16 => 0, u32 => 0, u64 => 0, u128 => 0, usize => 0,
+    i8 => 0, i16 => 0, i32 => 0, i64 => 0, i128 => 0, isize => 0,
+    f32 => 0.0, f64 => 0.0
+);
+
+/// A type with a total ordering, whose values may be compared by [`ConstList::max_item`]
+/// and [`ConstList::min_item`].
+pub trait ConstOrd {
+    /// Compares two values.
+    fn const_cmp(&self, other: &Self) -> Ordering;
+}
+
+/// Implements [`ConstOrd`] for primitive types.
+macro_rules! impl_const_ord {
+    ($($ty:ty),*) => {
+        $(
+            impl ConstOrd for $ty {
+                #[inline(always)]
+                fn const_cmp(&self, other: &Self) -> Ordering {
+                    if self < other {
+                        Ordering::Less
+                    } else if self > other {
+                        Ordering::Greater
+                    } else {
+                        Ordering::Equal
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_const_ord!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, char, bool);
 
 /// A singly-linked list of items that may be created in `const` contexts.
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone)]
 pub struct ConstList<'a, T: 'a>(Option<ConstListItem<'a, T>>);
 
 impl<'a, T: 'a> ConstList<'a, T> {
@@ -27,48 +300,71 @@ impl<'a, T: 'a> ConstList<'a, T> {
     }
 
     /// Gets a reference to the item at the provided index in this list, if any.
+    /// Unlike indexing with `list[index]`, this does not panic when out of bounds.
     #[inline(always)]
+    #[must_use]
     pub const fn get(&self, index: usize) -> Option<&T> {
-        if let Some(value) = &self.0 {
-            if index == 0 {
-                Some(&value.first)
-            } else {
-                value.rest.get(index - 1)
+        let mut list = self;
+        let mut remaining = index;
+        while let (Some(value), rest) = list.pop() {
+            if remaining == 0 {
+                return Some(value);
             }
-        } else {
-            None
+            list = rest;
+            remaining -= 1;
         }
+        None
     }
 
-    /// Determines the length of this list.
+    /// Determines the length of this list. This takes constant time,
+    /// since each node records the length of the list it heads.
     #[inline(always)]
+    #[must_use]
     pub const fn len(&self) -> usize {
         if let Some(value) = &self.0 {
-            value.rest.len() + 1
+            value.len
         } else {
             0
         }
     }
 
-    /// Whether the list is empty.
+    /// Whether the list is empty. This always takes constant time.
+    #[inline(always)]
+    #[must_use]
     pub const fn is_empty(&self) -> bool {
         self.0.is_none()
     }
 
     /// Pushes a new item onto the beginning of this list,
-    /// producing a new list head.
+    /// producing a new list head. The new list only borrows
+    /// this one, so it may have a shorter lifetime than `'a`.
     #[inline(always)]
-    pub const fn push(&'a self, value: T) -> Self {
+    pub const fn push(&self, value: T) -> ConstList<'_, T> {
         ConstList(Some(ConstListItem {
             first: value,
+            len: self.len() + 1,
             rest: self,
         }))
     }
 
+    /// Pushes a new item onto the beginning of this list, unless the list already
+    /// has at least `max_len` items, in which case the item is returned as an error.
+    /// This always takes constant time.
+    #[inline(always)]
+    pub const fn try_push(&self, value: T, max_len: usize) -> Result<ConstList<'_, T>, T> {
+        if self.len() < max_len {
+            Ok(self.push(value))
+        } else {
+            Err(value)
+        }
+    }
+
     /// Removes the first item (if any) from this list, and produces
-    /// the rest of the list.
+    /// the rest of the list. When matching upon the result,
+    /// [`ConstList::split_first`] is usually more convenient.
     #[inline(always)]
-    pub const fn pop(&'a self) -> (Option<&T>, &'a Self) {
+    #[must_use]
+    pub const fn pop(&self) -> (Option<&T>, &Self) {
         if let Some(value) = &self.0 {
             (Some(&value.first), value.rest)
         } else {
@@ -78,44 +374,3649 @@ impl<'a, T: 'a> ConstList<'a, T> {
 
     /// Creates an iterator over the contents of the list.
     #[inline(always)]
-    pub const fn iter(&self) -> ConstListIterator<T> {
-        ConstListIterator { target: self }
+    pub const fn iter(&self) -> ConstListIterator<'_, T> {
+        ConstListIterator {
+            target: self,
+            remaining: self.len(),
+        }
     }
-}
 
-impl<'a, T> IntoIterator for &'a ConstList<'a, T> {
-    type Item = &'a T;
+    /// Determines whether any item in this list satisfies the provided predicate.
+    #[inline(always)]
+    pub fn contains_by(&self, f: fn(&T) -> bool) -> bool {
+        let mut list = self;
+        while let (Some(value), rest) = list.pop() {
+            if f(value) {
+                return true;
+            }
+            list = rest;
+        }
+        false
+    }
 
-    type IntoIter = ConstListIterator<'a, T>;
+    /// Gets the index of the first item in this list which satisfies the provided predicate, if any.
+    #[inline(always)]
+    pub fn position_by(&self, f: fn(&T) -> bool) -> Option<usize> {
+        let mut list = self;
+        let mut index = 0;
+        while let (Some(value), rest) = list.pop() {
+            if f(value) {
+                return Some(index);
+            }
+            list = rest;
+            index += 1;
+        }
+        None
+    }
 
+    /// Combines the items of this list into an accumulator, from head to tail.
     #[inline(always)]
-    fn into_iter(self) -> Self::IntoIter {
-        ConstListIterator { target: self }
+    pub fn fold<B: Copy>(&self, init: B, f: fn(B, &T) -> B) -> B {
+        let mut list = self;
+        let mut acc = init;
+        while let (Some(value), rest) = list.pop() {
+            acc = f(acc, value);
+            list = rest;
+        }
+        acc
     }
-}
 
-/// A linked list node in a `ConstList`.
-#[derive(Copy, Clone, Debug)]
-struct ConstListItem<'a, T: 'a> {
-    /// The item represented by this node.
-    first: T,
-    /// The rest of the list.
-    rest: &'a ConstList<'a, T>,
-}
+    /// Determines whether every item in this list satisfies the provided predicate.
+    /// This is always true for an empty list.
+    #[inline(always)]
+    pub fn all(&self, f: fn(&T) -> bool) -> bool {
+        let mut list = self;
+        while let (Some(value), rest) = list.pop() {
+            if !f(value) {
+                return false;
+            }
+            list = rest;
+        }
+        true
+    }
 
-/// Iterates over the contents of a `ConstList`.
-pub struct ConstListIterator<'a, T> {
-    /// The current list head.
-    target: &'a ConstList<'a, T>,
-}
+    /// Determines whether any item in this list satisfies the provided predicate.
+    /// This is always false for an empty list.
+    #[inline(always)]
+    pub fn any(&self, f: fn(&T) -> bool) -> bool {
+        self.contains_by(f)
+    }
 
-impl<'a, T> Iterator for ConstListIterator<'a, T> {
-    type Item = &'a T;
+    /// Copies the items of this list into an array, from head to tail.
+    /// Panics if the list does not contain exactly `N` items.
+    #[inline(always)]
+    pub const fn to_array<const N: usize>(&self) -> [T; N]
+    where
+        T: Copy,
+    {
+        assert!(self.len() == N, "list length does not match array length");
+        self.copy_prefix()
+    }
 
+    /// Copies the first `N` items of this list into an array.
+    /// Panics if the list contains fewer than `N` items.
     #[inline(always)]
-    fn next(&mut self) -> Option<Self::Item> {
-        let (first, rest) = self.target.pop();
-        self.target = rest;
-        first
+    const fn copy_prefix<const N: usize>(&self) -> [T; N]
+    where
+        T: Copy,
+    {
+        let mut result = if let Some(first) = self.first() {
+            [*first; N]
+        } else {
+            // An empty slice only has a first chunk of length zero, so this succeeds only when `N == 0`.
+            let empty: [T; 0] = [];
+            if let Some(result) = empty.as_slice().first_chunk::<N>() {
+                *result
+            } else {
+                panic!("list is too short")
+            }
+        };
+
+        let mut list = self;
+        let mut index = 0;
+        while index < N {
+            if let (Some(value), rest) = list.pop() {
+                result[index] = *value;
+                list = rest;
+                index += 1;
+            } else {
+                panic!("list is too short")
+            }
+        }
+        result
+    }
+
+    /// Copies as many items of this list as will fit into `out`, from head to tail.
+    /// Returns the number of items written.
+    #[inline(always)]
+    pub const fn copy_into(&self, out: &mut [T]) -> usize
+    where
+        T: Copy,
+    {
+        let mut list = self;
+        let mut index = 0;
+        while index < out.len() {
+            if let (Some(value), rest) = list.pop() {
+                out[index] = *value;
+                list = rest;
+                index += 1;
+            } else {
+                break;
+            }
+        }
+        index
+    }
+
+    /// Clones the items of this list into a new vector, from head to tail.
+    #[cfg(feature = "alloc")]
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let mut result = Vec::with_capacity(self.len());
+        result.extend(self.iter().cloned());
+        result
+    }
+
+    /// Gets the item at index `n` in this list, along with an iterator over the items after it.
+    /// If `n` is out of bounds, then the item is `None` and the iterator is empty.
+    #[inline(always)]
+    pub const fn nth(&self, n: usize) -> (Option<&T>, ConstListIterator<'_, T>) {
+        let (value, rest) = self.skip(n).pop();
+        (value, rest.iter())
+    }
+
+    /// Gets a reference to the first item in this list, if any.
+    #[inline(always)]
+    pub const fn first(&self) -> Option<&T> {
+        self.pop().0
+    }
+
+    /// Gets a reference to the last item in this list, if any.
+    #[inline(always)]
+    pub const fn last(&self) -> Option<&T> {
+        let mut list = self;
+        let mut result = None;
+        while let (Some(value), rest) = list.pop() {
+            result = Some(value);
+            list = rest;
+        }
+        result
+    }
+
+    /// Splits this list into its last item and an iterator over the items before it,
+    /// or returns `None` if the list is empty.
+    #[inline(always)]
+    pub const fn split_last(&self) -> Option<(&T, ConstListIterator<'_, T>)> {
+        if let Some(last) = self.last() {
+            Some((
+                last,
+                ConstListIterator {
+                    target: self,
+                    remaining: self.len() - 1,
+                },
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Determines whether the items of `prefix` match the first items of this list.
+    #[inline(always)]
+    pub fn starts_with(&self, prefix: &ConstList<T>) -> bool
+    where
+        T: PartialEq,
+    {
+        prefix.len() <= self.len() && self.iter().zip(prefix).all(|(a, b)| a == b)
+    }
+
+    /// Counts the number of leading items which this list shares with `other`.
+    #[inline(always)]
+    pub fn common_prefix_len(&self, other: &ConstList<T>) -> usize
+    where
+        T: PartialEq,
+    {
+        self.iter().zip(other).take_while(|(a, b)| a == b).count()
+    }
+
+    /// Copies as many items of this list as will fit into `out`, from tail to head.
+    /// Returns the number of items written.
+    #[inline(always)]
+    pub const fn reverse_into(&self, out: &mut [T]) -> usize
+    where
+        T: Copy,
+    {
+        let len = self.len();
+        let count = if len < out.len() { len } else { out.len() };
+
+        let mut list = self;
+        let mut index = len;
+        while let (Some(value), rest) = list.pop() {
+            index -= 1;
+            if index < count {
+                out[index] = *value;
+            }
+            list = rest;
+        }
+        count
+    }
+
+    /// Creates an iterator over the contents of the list, from tail to head.
+    /// Each step walks the list from its head, so iterating over the
+    /// entire list takes `O(n^2)` time.
+    #[inline(always)]
+    pub fn rev(&self) -> Rev<ConstListIterator<'_, T>> {
+        self.iter().rev()
+    }
+
+    /// Creates an iterator over the contents of this list, followed by the contents of `other`.
+    #[inline(always)]
+    pub const fn chain<'b>(&'b self, other: &'b ConstList<T>) -> ConstListChain<'b, T> {
+        ConstListChain {
+            first: self.iter(),
+            second: other.iter(),
+        }
+    }
+
+    /// Creates an iterator over pairs of items from this list and `other`,
+    /// which ends once either list is exhausted.
+    #[inline(always)]
+    pub const fn zip<'b, U>(&'b self, other: &'b ConstList<U>) -> ConstListZip<'b, T, U> {
+        ConstListZip {
+            first: self.iter(),
+            second: other.iter(),
+        }
+    }
+
+    /// Combines the items of this list, from head to tail, using the first item
+    /// as the initial accumulator. Returns `None` if the list is empty.
+    #[inline(always)]
+    pub fn reduce(&self, f: fn(&T, &T) -> T) -> Option<T>
+    where
+        T: Copy,
+    {
+        let (first, mut list) = self.pop();
+        let mut acc = *first?;
+        while let (Some(value), rest) = list.pop() {
+            acc = f(&acc, value);
+            list = rest;
+        }
+        Some(acc)
+    }
+
+    /// Gets the maximum item in this list according to the provided comparison,
+    /// or `None` if the list is empty. If several items are equally maximum,
+    /// the last one is returned.
+    #[inline(always)]
+    pub fn max_by(&self, cmp: fn(&T, &T) -> Ordering) -> Option<&T> {
+        let (first, mut list) = self.pop();
+        let mut result = first?;
+        while let (Some(value), rest) = list.pop() {
+            if cmp(value, result).is_ge() {
+                result = value;
+            }
+            list = rest;
+        }
+        Some(result)
+    }
+
+    /// Gets the minimum item in this list according to the provided comparison,
+    /// or `None` if the list is empty. If several items are equally minimum,
+    /// the first one is returned.
+    #[inline(always)]
+    pub fn min_by(&self, cmp: fn(&T, &T) -> Ordering) -> Option<&T> {
+        let (first, mut list) = self.pop();
+        let mut result = first?;
+        while let (Some(value), rest) = list.pop() {
+            if cmp(value, result).is_lt() {
+                result = value;
+            }
+            list = rest;
+        }
+        Some(result)
+    }
+
+    /// Sums the items of this list, returning zero for an empty list.
+    #[inline(always)]
+    pub fn sum(&self) -> T
+    where
+        T: ConstAdd,
+    {
+        let mut list = self;
+        let mut acc = T::ZERO;
+        while let (Some(value), rest) = list.pop() {
+            acc = acc.const_add(*value);
+            list = rest;
+        }
+        acc
+    }
+
+    /// Counts the number of items in this list which satisfy the provided predicate.
+    #[inline(always)]
+    pub fn count_by(&self, f: fn(&T) -> bool) -> usize {
+        let mut list = self;
+        let mut count = 0;
+        while let (Some(value), rest) = list.pop() {
+            if f(value) {
+                count += 1;
+            }
+            list = rest;
+        }
+        count
+    }
+
+    /// Determines whether each adjacent pair of items is in order, according to the provided
+    /// function. This is always true for lists with fewer than two items.
+    #[inline(always)]
+    pub fn is_sorted_by(&self, cmp: fn(&T, &T) -> bool) -> bool {
+        let (first, mut list) = self.pop();
+        if let Some(mut previous) = first {
+            while let (Some(value), rest) = list.pop() {
+                if !cmp(previous, value) {
+                    return false;
+                }
+                previous = value;
+                list = rest;
+            }
+        }
+        true
+    }
+
+    /// Creates an iterator over copies of the contents of the list.
+    #[inline(always)]
+    pub fn copied_iter(&self) -> Copied<ConstListIterator<'_, T>>
+    where
+        T: Copy,
+    {
+        self.iter().copied()
+    }
+
+    /// Creates an iterator over each overlapping window of `W` consecutive items, from head to tail.
+    /// Each window is copied into a new array, so iterating over the entire list takes `O(n * W)` time.
+    /// Panics if `W` is zero.
+    #[inline(always)]
+    pub const fn windows<const W: usize>(&self) -> ConstListWindows<'_, T, W>
+    where
+        T: Copy,
+    {
+        assert!(W != 0, "window size must be non-zero");
+        ConstListWindows { list: self }
+    }
+
+    /// Creates a pair of iterators, over the first `k` items of this list and over the rest.
+    /// If `k` is at least the length of the list, the second iterator is empty.
+    #[inline(always)]
+    pub const fn split_at(&self, k: usize) -> (ConstListIterator<'_, T>, ConstListIterator<'_, T>) {
+        let rest = self.skip(k);
+        (
+            ConstListIterator {
+                target: self,
+                remaining: self.len() - rest.len(),
+            },
+            rest.iter(),
+        )
+    }
+
+    /// Creates an iterator over the contents of the list, which skips
+    /// any item equal to the one yielded immediately before it.
+    #[inline(always)]
+    pub const fn dedup_iter(&self) -> ConstListDedup<'_, T>
+    where
+        T: PartialEq,
+    {
+        ConstListDedup {
+            items: self.iter(),
+            previous: None,
+        }
+    }
+
+    /// Creates an iterator over the contents of the list, which yields
+    /// `separator` between each pair of adjacent items.
+    #[inline(always)]
+    pub const fn intersperse<'b>(&'b self, separator: &'b T) -> ConstListIntersperse<'b, T> {
+        ConstListIntersperse {
+            items: self.iter(),
+            separator,
+            needs_separator: false,
+        }
+    }
+
+    /// Finds the indices of the items in this list which satisfy the provided predicate, in ascending order.
+    /// Returns an array holding the first `N` such indices, along with the number of indices written.
+    /// If more than `N` items match, the search stops and `N` is reported.
+    #[inline(always)]
+    pub fn indices_of_by<const N: usize>(&self, f: fn(&T) -> bool) -> ([usize; N], usize) {
+        let mut result = [0; N];
+        let mut count = 0;
+        let mut list = self;
+        let mut index = 0;
+        while let (Some(value), rest) = list.pop() {
+            if f(value) {
+                if count == N {
+                    break;
+                }
+                result[count] = index;
+                count += 1;
+            }
+            list = rest;
+            index += 1;
+        }
+        (result, count)
+    }
+
+    /// Combines the items of this list into an accumulator, from head to tail,
+    /// stopping and returning `None` as soon as `f` does.
+    #[inline(always)]
+    pub fn try_fold<B: Copy>(&self, init: B, f: fn(B, &T) -> Option<B>) -> Option<B> {
+        let mut list = self;
+        let mut acc = init;
+        while let (Some(value), rest) = list.pop() {
+            acc = f(acc, value)?;
+            list = rest;
+        }
+        Some(acc)
+    }
+
+    /// Copies the items of this list which satisfy the provided predicate into one array,
+    /// and the remaining items into another, preserving their order. Returns each array
+    /// along with the number of items written to it. Items which do not fit in their
+    /// array are discarded, and unused slots are left as `T::default()`.
+    #[inline(always)]
+    pub fn partition<const N: usize>(&self, f: fn(&T) -> bool) -> ([T; N], usize, [T; N], usize)
+    where
+        T: Copy + Default,
+    {
+        let mut matching = [T::default(); N];
+        let mut matching_count = 0;
+        let mut rest_items = [T::default(); N];
+        let mut rest_count = 0;
+
+        for value in self {
+            if f(value) {
+                if matching_count < N {
+                    matching[matching_count] = *value;
+                    matching_count += 1;
+                }
+            } else if rest_count < N {
+                rest_items[rest_count] = *value;
+                rest_count += 1;
+            }
+        }
+
+        (matching, matching_count, rest_items, rest_count)
+    }
+
+    /// Gets a reference to the item at the provided index in this list, counting back from the tail, if any.
+    #[inline(always)]
+    pub const fn get_back(&self, index: usize) -> Option<&T> {
+        let len = self.len();
+        if index < len {
+            self.get(len - index - 1)
+        } else {
+            None
+        }
+    }
+
+    /// Applies `f` to each item of this list, from head to tail, and writes the results into an array.
+    /// Returns the array along with the number of results written. Items beyond the first `N` are
+    /// not visited, and unused slots are left as `U::default()`.
+    #[inline(always)]
+    pub fn map_into<U, const N: usize>(&self, f: fn(&T) -> U) -> ([U; N], usize)
+    where
+        U: Default,
+    {
+        let mut result = core::array::from_fn(|_| U::default());
+        let mut count = 0;
+        for value in self.iter().take(N) {
+            result[count] = f(value);
+            count += 1;
+        }
+        (result, count)
+    }
+
+    /// Copies the items of this list which satisfy the provided predicate into an array, from head to tail.
+    /// Returns the array along with the number of items written. Matching items beyond the first `N`
+    /// are discarded, and unused slots are left as `T::default()`.
+    #[inline(always)]
+    pub fn filter_into<const N: usize>(&self, f: fn(&T) -> bool) -> ([T; N], usize)
+    where
+        T: Copy + Default,
+    {
+        let mut result = [T::default(); N];
+        let mut count = 0;
+        let mut list = self;
+        while let (Some(value), rest) = list.pop() {
+            if count == N {
+                break;
+            } else if f(value) {
+                result[count] = *value;
+                count += 1;
+            }
+            list = rest;
+        }
+        (result, count)
+    }
+
+    /// Determines whether this list and `other` have the same length,
+    /// and whether each pair of corresponding items is equal according to `f`.
+    #[inline(always)]
+    pub fn eq_by(&self, other: &ConstList<T>, f: fn(&T, &T) -> bool) -> bool {
+        if self.len() != other.len() {
+            return false;
+        }
+
+        let mut list = self;
+        let mut other = other;
+        while let ((Some(a), rest), (Some(b), other_rest)) = (list.pop(), other.pop()) {
+            if !f(a, b) {
+                return false;
+            }
+            list = rest;
+            other = other_rest;
+        }
+        true
+    }
+
+    /// Creates an iterator over non-overlapping chunks of `C` consecutive items, from head to tail.
+    /// Each chunk is yielded along with the number of items it holds, which is less than `C` only
+    /// for the final chunk. Unused slots in that chunk are left as `T::default()`.
+    /// Panics if `C` is zero.
+    #[inline(always)]
+    pub const fn chunks<const C: usize>(&self) -> ConstListChunks<'_, T, C>
+    where
+        T: Copy + Default,
+    {
+        assert!(C != 0, "chunk size must be non-zero");
+        ConstListChunks { items: self.iter() }
+    }
+
+    /// Creates an iterator over the contents of the list, along with the index of each item.
+    #[inline(always)]
+    pub fn iter_indexed(&self) -> Enumerate<ConstListIterator<'_, T>> {
+        self.iter().enumerate()
+    }
+
+    /// Applies `f` to each item of this list, from head to tail, along with a mutable state
+    /// initialized to `init`, and writes the results into an array. Returns the array along with
+    /// the number of results written. Items beyond the first `N` are not visited, and unused
+    /// slots are left as `U::default()`.
+    #[inline(always)]
+    pub fn scan_into<S: Copy, U, const N: usize>(
+        &self,
+        init: S,
+        f: fn(&mut S, &T) -> U,
+    ) -> ([U; N], usize)
+    where
+        U: Default,
+    {
+        let mut result = core::array::from_fn(|_| U::default());
+        let mut state = init;
+        let mut count = 0;
+        for value in self.iter().take(N) {
+            result[count] = f(&mut state, value);
+            count += 1;
+        }
+        (result, count)
+    }
+
+    /// Creates an iterator over each run of equal adjacent items in this list,
+    /// yielding the first item of each run along with the run's length.
+    #[inline(always)]
+    pub const fn group_runs(&self) -> ConstListGroupRuns<'_, T>
+    where
+        T: PartialEq,
+    {
+        ConstListGroupRuns { list: self }
+    }
+
+    /// Copies the first `K` items of this list into an array,
+    /// or returns `None` if the list contains fewer than `K` items.
+    #[inline(always)]
+    pub const fn first_chunk<const K: usize>(&self) -> Option<[T; K]>
+    where
+        T: Copy,
+    {
+        if self.len() < K {
+            None
+        } else {
+            Some(self.copy_prefix())
+        }
+    }
+
+    /// Copies the last `K` items of this list into an array,
+    /// or returns `None` if the list contains fewer than `K` items.
+    #[inline(always)]
+    pub const fn last_chunk<const K: usize>(&self) -> Option<[T; K]>
+    where
+        T: Copy,
+    {
+        if self.len() < K {
+            None
+        } else {
+            Some(self.skip(self.len() - K).copy_prefix())
+        }
+    }
+
+    /// Gets the index of the last item in this list which satisfies the provided predicate, if any.
+    /// The index is still counted from the head of the list.
+    #[inline(always)]
+    pub fn position_back_by(&self, f: fn(&T) -> bool) -> Option<usize> {
+        let mut list = self;
+        let mut index = 0;
+        let mut result = None;
+        while let (Some(value), rest) = list.pop() {
+            if f(value) {
+                result = Some(index);
+            }
+            list = rest;
+            index += 1;
+        }
+        result
+    }
+
+    /// Copies the items of each list in `lists`, in order, into `out`, stopping once
+    /// `out` is full. Returns the number of items written.
+    #[inline(always)]
+    pub const fn concat_into(lists: &[&ConstList<T>], out: &mut [T]) -> usize
+    where
+        T: Copy,
+    {
+        let mut written = 0;
+        let mut index = 0;
+        while index < lists.len() {
+            written += lists[index].copy_into(out.split_at_mut(written).1);
+            index += 1;
+        }
+        written
+    }
+
+    /// Applies `f` to each item of this list, from head to tail,
+    /// and returns the first result which is not `None`.
+    #[inline(always)]
+    pub fn find_map_by<U: Copy>(&self, f: fn(&T) -> Option<U>) -> Option<U> {
+        let mut list = self;
+        while let (Some(value), rest) = list.pop() {
+            if let Some(result) = f(value) {
+                return Some(result);
+            }
+            list = rest;
+        }
+        None
+    }
+
+    /// Creates an iterator over at most the first `n` items of this list.
+    #[inline(always)]
+    pub const fn truncate_iter(&self, n: usize) -> ConstListIterator<'_, T> {
+        let len = self.len();
+        ConstListIterator {
+            target: self,
+            remaining: if n < len { n } else { len },
+        }
+    }
+
+    /// Gets the rest of this list after skipping its first `n` items. If `n` is
+    /// at least the length of the list, then the returned list is empty.
+    #[inline(always)]
+    pub const fn skip(&self, n: usize) -> &Self {
+        let mut list = self;
+        let mut index = 0;
+        while index < n && !list.is_empty() {
+            list = list.pop().1;
+            index += 1;
+        }
+        list
+    }
+
+    /// Clones the items of this list into a new vector, from tail to head.
+    #[cfg(feature = "alloc")]
+    pub fn to_vec_rev(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let mut result = self.to_vec();
+        result.reverse();
+        result
+    }
+
+    /// Copies the items of this list which satisfy the provided predicate into an array, along with
+    /// their indices. Returns the array along with the number of items written. Matching items beyond
+    /// the first `N` are discarded, and unused slots are left as `(0, T::default())`.
+    #[inline(always)]
+    pub fn retain_into<const N: usize>(&self, f: fn(&T) -> bool) -> ([(usize, T); N], usize)
+    where
+        T: Copy + Default,
+    {
+        let mut result = [(0, T::default()); N];
+        let mut count = 0;
+        let mut list = self;
+        let mut index = 0;
+        while let (Some(value), rest) = list.pop() {
+            if count == N {
+                break;
+            } else if f(value) {
+                result[count] = (index, *value);
+                count += 1;
+            }
+            list = rest;
+            index += 1;
+        }
+        (result, count)
+    }
+
+    /// Counts the items in this list by walking every node. This always agrees with
+    /// [`ConstList::len`], which should be preferred since it takes constant time.
+    #[inline(always)]
+    #[must_use]
+    pub const fn count(&self) -> usize {
+        let mut list = self;
+        let mut count = 0;
+        while let (Some(_), rest) = list.pop() {
+            list = rest;
+            count += 1;
+        }
+        count
+    }
+
+    /// Pushes `items[index]` onto the beginning of this list, or copies this list if `index`
+    /// is out of bounds. This allows [`push_array!`] and [`const_list_from!`] to unroll arrays
+    /// of unknown length.
+    #[doc(hidden)]
+    #[inline(always)]
+    pub const fn push_index(&self, items: &[T], index: usize) -> ConstList<'_, T>
+    where
+        T: Copy,
+    {
+        if index < items.len() {
+            self.push(items[index])
+        } else {
+            *self
+        }
+    }
+
+    /// Creates an iterator over the contents of the list, which stops
+    /// permanently at the first item that does not satisfy the provided predicate.
+    #[inline(always)]
+    pub const fn iter_while(&self, f: fn(&T) -> bool) -> ConstListIterWhile<'_, T> {
+        ConstListIterWhile {
+            items: self.iter(),
+            predicate: f,
+        }
+    }
+
+    /// Gets the maximum item in this list, or `None` if the list is empty.
+    /// If several items are equally maximum, the last one is returned.
+    #[inline(always)]
+    pub fn max_item(&self) -> Option<&T>
+    where
+        T: ConstOrd,
+    {
+        self.max_by(T::const_cmp)
+    }
+
+    /// Gets the minimum item in this list, or `None` if the list is empty.
+    /// If several items are equally minimum, the first one is returned.
+    #[inline(always)]
+    pub fn min_item(&self) -> Option<&T>
+    where
+        T: ConstOrd,
+    {
+        self.min_by(T::const_cmp)
+    }
+
+    /// Splits this list into its first item and the rest of the list,
+    /// or returns `None` if the list is empty.
+    #[inline(always)]
+    pub const fn split_first(&self) -> Option<(&T, &Self)> {
+        if let Some(value) = &self.0 {
+            Some((&value.first, value.rest))
+        } else {
+            None
+        }
+    }
+
+    /// Gets the first item in this list which satisfies the provided predicate, along with its index, if any.
+    #[inline(always)]
+    pub fn find_indexed(&self, f: fn(&T) -> bool) -> Option<(usize, &T)> {
+        let mut list = self;
+        let mut index = 0;
+        while let (Some(value), rest) = list.pop() {
+            if f(value) {
+                return Some((index, value));
+            }
+            list = rest;
+            index += 1;
+        }
+        None
+    }
+
+    /// Copies the first occurrence of each distinct item in this list into `out`, from head to tail,
+    /// stopping once `out` is full. Returns the number of items written. Each item is compared
+    /// against all of those written before it, so this takes `O(n^2)` time.
+    #[inline(always)]
+    pub fn dedup_all_into(&self, out: &mut [T]) -> usize
+    where
+        T: PartialEq + Copy,
+    {
+        let mut count = 0;
+        for value in self {
+            if count == out.len() {
+                break;
+            } else if !out[..count].contains(value) {
+                out[count] = *value;
+                count += 1;
+            }
+        }
+        count
+    }
+
+    /// Creates an iterator over the contents of the list, starting from the item at index `start`.
+    #[inline(always)]
+    pub const fn iter_from(&self, start: usize) -> ConstListIterator<'_, T> {
+        self.skip(start).iter()
+    }
+
+    /// Lexicographically compares this list with `other`, comparing items according to `cmp`.
+    /// If one list is a prefix of the other, the shorter list is ordered first.
+    #[inline(always)]
+    pub fn cmp_by(&self, other: &ConstList<T>, cmp: fn(&T, &T) -> Ordering) -> Ordering {
+        let mut list = self;
+        let mut other = other;
+        loop {
+            match (list.pop(), other.pop()) {
+                ((Some(a), rest), (Some(b), other_rest)) => {
+                    let ordering = cmp(a, b);
+                    if ordering.is_ne() {
+                        return ordering;
+                    }
+                    list = rest;
+                    other = other_rest;
+                }
+                ((Some(_), _), (None, _)) => return Ordering::Greater,
+                ((None, _), (Some(_), _)) => return Ordering::Less,
+                ((None, _), (None, _)) => return Ordering::Equal,
+            }
+        }
+    }
+
+    /// Gets a reference to the `n`th item from the tail of this list, if any.
+    /// This is equivalent to [`ConstList::get_back`].
+    #[inline(always)]
+    pub const fn nth_back(&self, n: usize) -> Option<&T> {
+        self.get_back(n)
+    }
+
+    /// Gets a reference to the item at the provided index in this list,
+    /// or an error describing why the index is out of bounds.
+    #[inline(always)]
+    pub const fn try_get(&self, index: usize) -> Result<&T, ConstListError> {
+        if let Some(value) = self.get(index) {
+            Ok(value)
+        } else {
+            Err(ConstListError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            })
+        }
+    }
+
+    /// Writes the running total of this list's items into `out`, from head to tail,
+    /// stopping once `out` is full. Returns the number of totals written.
+    #[inline(always)]
+    pub fn prefix_sums_into(&self, out: &mut [T]) -> usize
+    where
+        T: ConstAdd,
+    {
+        let mut list = self;
+        let mut acc = T::ZERO;
+        let mut count = 0;
+        while count < out.len() {
+            if let (Some(value), rest) = list.pop() {
+                acc = acc.const_add(*value);
+                out[count] = acc;
+                list = rest;
+                count += 1;
+            } else {
+                break;
+            }
+        }
+        count
+    }
+
+    /// Creates an iterator over clones of the contents of the list, from tail to head.
+    /// Unlike [`ConstList::rev`], this takes `O(n)` time overall, but allocates
+    /// and clones every item up front.
+    #[cfg(feature = "alloc")]
+    pub fn rev_iter(&self) -> alloc::vec::IntoIter<T>
+    where
+        T: Clone,
+    {
+        self.to_vec_rev().into_iter()
+    }
+
+    /// Determines whether any item in this list is equal to `value`.
+    #[inline(always)]
+    pub fn contains(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|item| item == value)
+    }
+
+    /// Creates an iterator over each pair of adjacent items in this list.
+    #[inline(always)]
+    pub fn pairs(&self) -> Zip<ConstListIterator<'_, T>, ConstListIterator<'_, T>> {
+        self.iter().zip(self.iter_from(1))
+    }
+
+    /// Calls `f` with the index and value of each item in this list, from head to tail.
+    #[inline(always)]
+    pub fn for_each_indexed(&self, mut f: impl FnMut(usize, &T)) {
+        for (index, value) in self.iter().enumerate() {
+            f(index, value);
+        }
+    }
+
+    /// Creates an iterator over the segments of this list which are separated by items
+    /// satisfying `is_sep`. The separators are not included in any segment. As with
+    /// [`slice::split`], a list with `k` separators always yields `k + 1` segments, so
+    /// consecutive separators are divided by an empty segment, a leading or trailing separator
+    /// produces an empty first or last segment, and an empty list yields one empty segment.
+    ///
+    /// ```rust
+    /// # use const_list::*;
+    /// const LIST: ConstList<'static, i32> = const_list![0, 1, 0, 0, 2];
+    /// let segments = LIST
+    ///     .split_on(|x| *x == 0)
+    ///     .map(|segment| segment.count())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(segments, [0, 1, 0, 1]);
+    /// ```
+    #[inline(always)]
+    pub const fn split_on(&self, is_sep: fn(&T) -> bool) -> ConstListSplit<'_, T> {
+        ConstListSplit {
+            items: self.iter(),
+            predicate: is_sep,
+            finished: false,
+        }
+    }
+
+    /// Determines whether this list has exactly `N` items, and whether each item is equal
+    /// to the corresponding item of `array` according to `cmp`.
+    #[inline(always)]
+    pub fn equals_array<const N: usize>(&self, array: &[T; N], cmp: fn(&T, &T) -> bool) -> bool {
+        self.len() == N && self.iter().zip(array).all(|(a, b)| cmp(a, b))
+    }
+
+    /// Gets the first item in this list along with the number of items after it,
+    /// or returns `None` if the list is empty.
+    #[inline(always)]
+    pub const fn head_tail(&self) -> Option<(&T, usize)> {
+        if let Some(value) = &self.0 {
+            Some((&value.first, value.len - 1))
+        } else {
+            None
+        }
+    }
+
+    /// Copies pairs of corresponding items from this list and `other` into `out`, from head to tail,
+    /// until either list or `out` is exhausted. Returns the number of pairs written.
+    #[inline(always)]
+    pub fn zip_into<U: Copy>(&self, other: &ConstList<U>, out: &mut [(T, U)]) -> usize
+    where
+        T: Copy,
+    {
+        let mut written = 0;
+        for (slot, (a, b)) in out.iter_mut().zip(self.iter().zip(other)) {
+            *slot = (*a, *b);
+            written += 1;
+        }
+        written
+    }
+
+    /// Determines whether the items of this list match the first items of `other`.
+    #[inline(always)]
+    pub fn is_prefix_of(&self, other: &ConstList<T>) -> bool
+    where
+        T: PartialEq,
+    {
+        other.starts_with(self)
+    }
+
+    /// Determines whether the items of this list match the last items of `other`.
+    #[inline(always)]
+    pub fn is_suffix_of(&self, other: &ConstList<T>) -> bool
+    where
+        T: PartialEq,
+    {
+        self.len() <= other.len() && other.skip(other.len() - self.len()) == self
+    }
+
+    /// Sums the result of applying `f` to each item of this list, returning zero for an empty list.
+    /// The addition wraps around on overflow.
+    #[inline(always)]
+    pub fn sum_by(&self, f: fn(&T) -> i64) -> i64 {
+        let mut list = self;
+        let mut acc = 0i64;
+        while let (Some(value), rest) = list.pop() {
+            acc = acc.wrapping_add(f(value));
+            list = rest;
+        }
+        acc
+    }
+
+    /// Creates an iterator over the indices of the items in this list which satisfy `f`, in ascending order.
+    #[inline(always)]
+    pub fn positions<F: FnMut(&T) -> bool>(&self, f: F) -> ConstListPositions<'_, T, F> {
+        ConstListPositions {
+            items: self.iter().enumerate(),
+            predicate: f,
+        }
+    }
+
+    /// Creates an iterator over the contents of the list, which can look ahead
+    /// at the next item without advancing.
+    #[inline(always)]
+    pub const fn peekable(&self) -> ConstListPeekable<'_, T> {
+        ConstListPeekable { items: self.iter() }
+    }
+
+    /// Determines whether this list has at least `n` items. This always takes constant time.
+    #[inline(always)]
+    pub const fn len_at_least(&self, n: usize) -> bool {
+        self.len() >= n
+    }
+
+    /// Determines whether this list has exactly `n` items. This always takes constant time.
+    #[inline(always)]
+    pub const fn len_exactly(&self, n: usize) -> bool {
+        self.len() == n
+    }
+
+    /// Applies `f` to each item of this list, from head to tail, and writes the two components
+    /// of each result into separate arrays. Returns both arrays along with the number of results
+    /// written. Items beyond the first `N` are not visited, and unused slots are left as
+    /// `A::default()` and `B::default()`.
+    #[inline(always)]
+    pub fn unzip_into<A, B, const N: usize>(&self, f: fn(&T) -> (A, B)) -> ([A; N], [B; N], usize)
+    where
+        A: Copy + Default,
+        B: Copy + Default,
+    {
+        let mut first = [A::default(); N];
+        let mut second = [B::default(); N];
+        let mut count = 0;
+        for value in self.iter().take(N) {
+            (first[count], second[count]) = f(value);
+            count += 1;
+        }
+        (first, second, count)
+    }
+
+    /// Fills `out` by repeating the items of this list from head to tail, as many times as needed.
+    /// Returns the number of items written, which is zero if this list is empty and `out.len()` otherwise.
+    #[inline(always)]
+    pub const fn cycle_into(&self, out: &mut [T]) -> usize
+    where
+        T: Copy,
+    {
+        if self.is_empty() {
+            return 0;
+        }
+
+        let mut list = self;
+        let mut index = 0;
+        while index < out.len() {
+            if let (Some(value), rest) = list.pop() {
+                out[index] = *value;
+                list = rest;
+                index += 1;
+            } else {
+                list = self;
+            }
+        }
+        index
+    }
+
+    /// Combines the items of this list into an accumulator, from head to tail,
+    /// passing the index of each item to `f` alongside it.
+    #[inline(always)]
+    pub fn fold_indexed<B: Copy>(&self, init: B, f: fn(B, usize, &T) -> B) -> B {
+        let mut list = self;
+        let mut acc = init;
+        let mut index = 0;
+        while let (Some(value), rest) = list.pop() {
+            acc = f(acc, index, value);
+            list = rest;
+            index += 1;
+        }
+        acc
+    }
+
+    /// Creates an iterator which clones each item of this list as it is yielded.
+    #[inline(always)]
+    pub fn iter_cloned(&self) -> Cloned<ConstListIterator<'_, T>>
+    where
+        T: Clone,
+    {
+        self.iter().cloned()
+    }
+
+    /// Copies every item of this list except the one at `index` into `out`, from head to tail,
+    /// stopping early if `out` is full. Returns the removed item along with the number of items
+    /// written, or `None` if `index` is out of bounds.
+    #[inline(always)]
+    pub const fn remove_into(&self, index: usize, out: &mut [T]) -> Option<(T, usize)>
+    where
+        T: Copy,
+    {
+        let removed = if let Some(value) = self.get(index) {
+            *value
+        } else {
+            return None;
+        };
+
+        let mut list = self;
+        let mut position = 0;
+        let mut written = 0;
+        while written < out.len() {
+            if let (Some(value), rest) = list.pop() {
+                if position != index {
+                    out[written] = *value;
+                    written += 1;
+                }
+                list = rest;
+                position += 1;
+            } else {
+                break;
+            }
+        }
+        Some((removed, written))
+    }
+
+    /// Copies the items of this list into `out`, from head to tail, with `value` inserted at `index`,
+    /// stopping early if `out` is full. Returns the number of items written, or `None` if `index`
+    /// is greater than the length of this list.
+    #[inline(always)]
+    pub const fn insert_into(&self, index: usize, value: T, out: &mut [T]) -> Option<usize>
+    where
+        T: Copy,
+    {
+        if index > self.len() {
+            return None;
+        }
+
+        let mut list = self;
+        let mut written = 0;
+        while written < out.len() {
+            if written == index {
+                out[written] = value;
+                written += 1;
+            } else if let (Some(item), rest) = list.pop() {
+                out[written] = *item;
+                list = rest;
+                written += 1;
+            } else {
+                break;
+            }
+        }
+        Some(written)
+    }
+
+    /// Determines the number of trailing items which this list physically shares with `other`.
+    /// Lists built by pushing onto a common base share that base, but lists built separately
+    /// usually share nothing, even if their items are equal. The compiler may still merge
+    /// identical constant lists into the same nodes.
+    #[inline(always)]
+    pub fn shared_tail_len(&self, other: &ConstList<T>) -> usize {
+        let len = self.len().min(other.len());
+        let mut list = self.skip(self.len() - len);
+        let mut other = other.skip(other.len() - len);
+        while !core::ptr::eq(list, other) {
+            if let ((Some(_), rest), (Some(_), other_rest)) = (list.pop(), other.pop()) {
+                list = rest;
+                other = other_rest;
+            } else {
+                break;
+            }
+        }
+        list.len()
+    }
+
+    /// Creates an iterator over the contents of the list, which yields each item
+    /// along with the rest of the list after it.
+    #[inline(always)]
+    pub const fn iter_with_rest(&self) -> ConstListWithRest<'_, T> {
+        ConstListWithRest { target: self }
+    }
+
+    /// Applies `f` to each item of this list, from head to tail, and copies the first `count` items
+    /// of each resulting `(array, count)` pair into `out`, stopping once `out` is full. Counts larger
+    /// than `K` are treated as `K`. Returns the total number of items written.
+    #[inline(always)]
+    pub fn flat_map_into<U: Copy, const K: usize>(
+        &self,
+        f: fn(&T) -> ([U; K], usize),
+        out: &mut [U],
+    ) -> usize {
+        let mut written = 0;
+        for value in self {
+            if written == out.len() {
+                break;
+            }
+
+            let (items, count) = f(value);
+            for item in &items[..count.min(K)] {
+                if written == out.len() {
+                    break;
+                }
+                out[written] = *item;
+                written += 1;
+            }
+        }
+        written
+    }
+
+    /// Creates a formatter which shows the node structure of this list, rather than just its items.
+    /// Each node is shown with its address, so shared tails can be recognized across lists,
+    /// and the chain ends with the address of the empty list.
+    #[inline(always)]
+    pub const fn debug_chain(&self) -> ConstListDebugChain<'_, T> {
+        ConstListDebugChain { target: self }
+    }
+
+    /// Pushes the item `index` places from the tail of `source` onto the beginning of this list,
+    /// or copies this list if `index` is out of bounds. This allows [`clone_list!`] to unroll
+    /// lists of unknown length.
+    #[doc(hidden)]
+    #[inline(always)]
+    pub const fn push_from_back(&self, source: &ConstList<T>, index: usize) -> ConstList<'_, T>
+    where
+        T: Copy,
+    {
+        if let Some(value) = source.get_back(index) {
+            self.push(*value)
+        } else {
+            *self
+        }
+    }
+}
+
+impl<'a, T: 'a> ConstList<'a, &'a ConstList<'a, T>> {
+    /// Creates an iterator over the contents of each list in this list, in order.
+    #[inline(always)]
+    pub const fn flatten(&self) -> ConstListFlatten<'_, T> {
+        ConstListFlatten {
+            outer: self.iter(),
+            inner: None,
+        }
+    }
+}
+
+impl<'a, T: 'a> Default for ConstList<'a, T> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, 'b, T> IntoIterator for &'b ConstList<'a, T> {
+    type Item = &'b T;
+
+    type IntoIter = ConstListIterator<'b, T>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        ConstListIterator {
+            target: self,
+            remaining: self.len(),
+        }
+    }
+}
+
+impl<'a, T> Index<usize> for ConstList<'a, T> {
+    type Output = T;
+
+    #[inline(always)]
+    fn index(&self, index: usize) -> &Self::Output {
+        match self.try_get(index) {
+            Ok(value) => value,
+            Err(error) => panic!("{error}"),
+        }
+    }
+}
+
+impl<'a, T: PartialEq> PartialEq for ConstList<'a, T> {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<'a, T: Eq> Eq for ConstList<'a, T> {}
+
+impl<'a, T: PartialEq> PartialEq<[T]> for ConstList<'a, T> {
+    #[inline(always)]
+    fn eq(&self, other: &[T]) -> bool {
+        self.len() == other.len() && self.iter().eq(other)
+    }
+}
+
+impl<'a, T: PartialEq> PartialEq<&[T]> for ConstList<'a, T> {
+    #[inline(always)]
+    fn eq(&self, other: &&[T]) -> bool {
+        *self == **other
+    }
+}
+
+impl<'a, T: PartialEq, const N: usize> PartialEq<[T; N]> for ConstList<'a, T> {
+    #[inline(always)]
+    fn eq(&self, other: &[T; N]) -> bool {
+        *self == other[..]
+    }
+}
+
+impl<'a, T: PartialEq> PartialEq<ConstList<'a, T>> for [T] {
+    #[inline(always)]
+    fn eq(&self, other: &ConstList<'a, T>) -> bool {
+        *other == *self
+    }
+}
+
+impl<'a, T: PartialEq> PartialEq<ConstList<'a, T>> for &[T] {
+    #[inline(always)]
+    fn eq(&self, other: &ConstList<'a, T>) -> bool {
+        *other == **self
+    }
+}
+
+impl<'a, T: PartialEq, const N: usize> PartialEq<ConstList<'a, T>> for [T; N] {
+    #[inline(always)]
+    fn eq(&self, other: &ConstList<'a, T>) -> bool {
+        *other == self[..]
+    }
+}
+
+impl<'a, T: PartialOrd> PartialOrd for ConstList<'a, T> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<'a, T: Ord> Ord for ConstList<'a, T> {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<'a, T: Hash> Hash for ConstList<'a, T> {
+    #[inline(always)]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+        for item in self {
+            item.hash(state);
+        }
+    }
+}
+
+impl<'a, T: Debug> Debug for ConstList<'a, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self).finish()
+    }
+}
+
+impl<'a, T: Display> Display for ConstList<'a, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str("[")?;
+        for (index, item) in self.iter().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+            Display::fmt(item, f)?;
+        }
+        f.write_str("]")
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'a, T: serde::Serialize> serde::Serialize for ConstList<'a, T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for item in self {
+            seq.serialize_element(item)?;
+        }
+        seq.end()
+    }
+}
+
+/// Describes why an operation on a `ConstList` failed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ConstListError {
+    /// An index was not less than the length of the list.
+    IndexOutOfBounds {
+        /// The index which was requested.
+        index: usize,
+        /// The length of the list.
+        len: usize,
+    },
+}
+
+impl Display for ConstListError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::IndexOutOfBounds { index, len } => write!(
+                f,
+                "index out of bounds: the len is {len} but the index is {index}"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for ConstListError {}
+
+/// A linked list node in a `ConstList`.
+#[derive(Copy, Clone, Debug)]
+struct ConstListItem<'a, T: 'a> {
+    /// The item represented by this node.
+    first: T,
+    /// The length of the list headed by this node.
+    len: usize,
+    /// The rest of the list.
+    rest: &'a ConstList<'a, T>,
+}
+
+/// Iterates over the contents of a `ConstList`.
+///
+/// Since the list is singly-linked, iterating from the back with
+/// [`DoubleEndedIterator::next_back`] must walk from the current head,
+/// and so takes `O(n)` time per item.
+pub struct ConstListIterator<'a, T> {
+    /// The current list head.
+    target: &'a ConstList<'a, T>,
+    /// The number of items which have not yet been yielded from either end.
+    remaining: usize,
+}
+
+// Implemented by hand, since deriving would needlessly require `T: Clone`.
+impl<'a, T> Clone for ConstListIterator<'a, T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for ConstListIterator<'a, T> {}
+
+impl<'a, T> Iterator for ConstListIterator<'a, T> {
+    type Item = &'a T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            None
+        } else {
+            let (first, rest) = self.target.pop();
+            self.target = rest;
+            self.remaining -= 1;
+            first
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+
+    #[inline(always)]
+    fn count(self) -> usize {
+        self.remaining
+    }
+
+    #[inline(always)]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n < self.remaining {
+            for _ in 0..n {
+                self.target = self.target.pop().1;
+            }
+            self.remaining -= n;
+            self.next()
+        } else {
+            self.remaining = 0;
+            None
+        }
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for ConstListIterator<'a, T> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            None
+        } else {
+            self.remaining -= 1;
+            self.target.get(self.remaining)
+        }
+    }
+}
+
+impl<'a, T> ExactSizeIterator for ConstListIterator<'a, T> {}
+
+impl<'a, T> FusedIterator for ConstListIterator<'a, T> {}
+
+/// Iterates over the contents of one `ConstList`, followed by another.
+pub struct ConstListChain<'a, T> {
+    /// The items of the first list.
+    first: ConstListIterator<'a, T>,
+    /// The items of the second list.
+    second: ConstListIterator<'a, T>,
+}
+
+impl<'a, T> Clone for ConstListChain<'a, T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for ConstListChain<'a, T> {}
+
+impl<'a, T> Iterator for ConstListChain<'a, T> {
+    type Item = &'a T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.first.next().or_else(|| self.second.next())
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.first.len() + self.second.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for ConstListChain<'a, T> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.second.next_back().or_else(|| self.first.next_back())
+    }
+}
+
+impl<'a, T> ExactSizeIterator for ConstListChain<'a, T> {}
+
+impl<'a, T> FusedIterator for ConstListChain<'a, T> {}
+
+/// Iterates over pairs of items from two `ConstList`s in lockstep.
+pub struct ConstListZip<'a, T, U> {
+    /// The items of the first list.
+    first: ConstListIterator<'a, T>,
+    /// The items of the second list.
+    second: ConstListIterator<'a, U>,
+}
+
+impl<'a, T, U> Clone for ConstListZip<'a, T, U> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T, U> Copy for ConstListZip<'a, T, U> {}
+
+impl<'a, T, U> Iterator for ConstListZip<'a, T, U> {
+    type Item = (&'a T, &'a U);
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if let (Some(first), Some(second)) = (self.first.next(), self.second.next()) {
+            Some((first, second))
+        } else {
+            None
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.first.len().min(self.second.len());
+        (len, Some(len))
+    }
+}
+
+impl<'a, T, U> ExactSizeIterator for ConstListZip<'a, T, U> {}
+
+impl<'a, T, U> FusedIterator for ConstListZip<'a, T, U> {}
+
+/// Iterates over the contents of each `ConstList` in a `ConstList` of lists.
+pub struct ConstListFlatten<'a, T> {
+    /// The lists which have not yet been visited.
+    outer: ConstListIterator<'a, &'a ConstList<'a, T>>,
+    /// The items of the list currently being visited.
+    inner: Option<ConstListIterator<'a, T>>,
+}
+
+impl<'a, T> Clone for ConstListFlatten<'a, T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for ConstListFlatten<'a, T> {}
+
+impl<'a, T> Iterator for ConstListFlatten<'a, T> {
+    type Item = &'a T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(value) = self.inner.as_mut().and_then(Iterator::next) {
+                return Some(value);
+            }
+            self.inner = Some(self.outer.next()?.iter());
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.inner.map_or(0, |inner| inner.len())
+            + self.outer.map(|list| list.len()).sum::<usize>();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> FusedIterator for ConstListFlatten<'a, T> {}
+
+/// Iterates over overlapping windows of `W` items in a `ConstList`.
+pub struct ConstListWindows<'a, T, const W: usize> {
+    /// The list headed by the next window.
+    list: &'a ConstList<'a, T>,
+}
+
+impl<'a, T, const W: usize> Clone for ConstListWindows<'a, T, W> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T, const W: usize> Copy for ConstListWindows<'a, T, W> {}
+
+impl<'a, T: Copy, const W: usize> Iterator for ConstListWindows<'a, T, W> {
+    type Item = [T; W];
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.list.len() < W {
+            None
+        } else {
+            let mut items = self.list.iter();
+            let window = core::array::from_fn(|_| *items.next().expect("window out of bounds"));
+            self.list = self.list.pop().1;
+            Some(window)
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.list.len() + 1).saturating_sub(W);
+        (len, Some(len))
+    }
+}
+
+impl<'a, T: Copy, const W: usize> ExactSizeIterator for ConstListWindows<'a, T, W> {}
+
+impl<'a, T: Copy, const W: usize> FusedIterator for ConstListWindows<'a, T, W> {}
+
+/// Iterates over the contents of a `ConstList`, skipping adjacent duplicates.
+pub struct ConstListDedup<'a, T> {
+    /// The items which have not yet been visited.
+    items: ConstListIterator<'a, T>,
+    /// The most recently yielded item.
+    previous: Option<&'a T>,
+}
+
+impl<'a, T> Clone for ConstListDedup<'a, T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for ConstListDedup<'a, T> {}
+
+impl<'a, T: PartialEq> Iterator for ConstListDedup<'a, T> {
+    type Item = &'a T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let value = self.items.next()?;
+            if self.previous != Some(value) {
+                self.previous = Some(value);
+                return Some(value);
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.items.len();
+        let lower = if self.previous.is_none() {
+            remaining.min(1)
+        } else {
+            0
+        };
+        (lower, Some(remaining))
+    }
+}
+
+impl<'a, T: PartialEq> FusedIterator for ConstListDedup<'a, T> {}
+
+/// Iterates over the contents of a `ConstList`, with a separator between adjacent items.
+pub struct ConstListIntersperse<'a, T> {
+    /// The items which have not yet been visited.
+    items: ConstListIterator<'a, T>,
+    /// The separator to yield between items.
+    separator: &'a T,
+    /// Whether the separator should be yielded before the next item.
+    needs_separator: bool,
+}
+
+impl<'a, T> Clone for ConstListIntersperse<'a, T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for ConstListIntersperse<'a, T> {}
+
+impl<'a, T> Iterator for ConstListIntersperse<'a, T> {
+    type Item = &'a T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.needs_separator && self.items.len() > 0 {
+            self.needs_separator = false;
+            Some(self.separator)
+        } else {
+            self.needs_separator = true;
+            self.items.next()
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.items.len();
+        let len = if remaining == 0 {
+            0
+        } else if self.needs_separator {
+            2 * remaining
+        } else {
+            2 * remaining - 1
+        };
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for ConstListIntersperse<'a, T> {}
+
+impl<'a, T> FusedIterator for ConstListIntersperse<'a, T> {}
+
+/// Iterates over non-overlapping chunks of `C` items in a `ConstList`.
+pub struct ConstListChunks<'a, T, const C: usize> {
+    /// The items which have not yet been visited.
+    items: ConstListIterator<'a, T>,
+}
+
+impl<'a, T, const C: usize> Clone for ConstListChunks<'a, T, C> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T, const C: usize> Copy for ConstListChunks<'a, T, C> {}
+
+impl<'a, T: Copy + Default, const C: usize> Iterator for ConstListChunks<'a, T, C> {
+    type Item = ([T; C], usize);
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.items.len() == 0 {
+            None
+        } else {
+            let mut chunk = [T::default(); C];
+            let mut count = 0;
+            for value in self.items.by_ref().take(C) {
+                chunk[count] = *value;
+                count += 1;
+            }
+            Some((chunk, count))
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.items.len().div_ceil(C);
+        (len, Some(len))
+    }
+}
+
+impl<'a, T: Copy + Default, const C: usize> ExactSizeIterator for ConstListChunks<'a, T, C> {}
+
+impl<'a, T: Copy + Default, const C: usize> FusedIterator for ConstListChunks<'a, T, C> {}
+
+/// Iterates over runs of equal adjacent items in a `ConstList`.
+pub struct ConstListGroupRuns<'a, T> {
+    /// The list headed by the next run.
+    list: &'a ConstList<'a, T>,
+}
+
+impl<'a, T> Clone for ConstListGroupRuns<'a, T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for ConstListGroupRuns<'a, T> {}
+
+impl<'a, T: PartialEq> Iterator for ConstListGroupRuns<'a, T> {
+    type Item = (&'a T, usize);
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (first, mut rest) = self.list.pop();
+        let first = first?;
+        let mut len = 1;
+        while rest.first() == Some(first) {
+            rest = rest.pop().1;
+            len += 1;
+        }
+        self.list = rest;
+        Some((first, len))
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.list.len();
+        (len.min(1), Some(len))
+    }
+}
+
+impl<'a, T: PartialEq> FusedIterator for ConstListGroupRuns<'a, T> {}
+
+/// Iterates over the contents of a `ConstList` while a predicate holds.
+pub struct ConstListIterWhile<'a, T> {
+    /// The items which have not yet been visited.
+    items: ConstListIterator<'a, T>,
+    /// The predicate which each yielded item must satisfy.
+    predicate: fn(&T) -> bool,
+}
+
+impl<'a, T> Clone for ConstListIterWhile<'a, T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for ConstListIterWhile<'a, T> {}
+
+impl<'a, T> Iterator for ConstListIterWhile<'a, T> {
+    type Item = &'a T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.items.next()?;
+        if (self.predicate)(value) {
+            Some(value)
+        } else {
+            self.items.remaining = 0;
+            None
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.items.len()))
+    }
+}
+
+impl<'a, T> FusedIterator for ConstListIterWhile<'a, T> {}
+
+/// Iterates over the segments of a `ConstList` which are separated by items satisfying a predicate.
+pub struct ConstListSplit<'a, T> {
+    /// The items which have not yet been visited.
+    items: ConstListIterator<'a, T>,
+    /// The predicate which identifies separators.
+    predicate: fn(&T) -> bool,
+    /// Whether the final segment has been yielded.
+    finished: bool,
+}
+
+impl<'a, T> Clone for ConstListSplit<'a, T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for ConstListSplit<'a, T> {}
+
+impl<'a, T> Iterator for ConstListSplit<'a, T> {
+    type Item = ConstListIterator<'a, T>;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        let mut segment = self.items;
+        segment.remaining = 0;
+        loop {
+            match self.items.next() {
+                Some(value) if (self.predicate)(value) => return Some(segment),
+                Some(_) => segment.remaining += 1,
+                None => {
+                    self.finished = true;
+                    return Some(segment);
+                }
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            (0, Some(0))
+        } else {
+            (1, Some(self.items.len() + 1))
+        }
+    }
+}
+
+impl<'a, T> FusedIterator for ConstListSplit<'a, T> {}
+
+/// Iterates over the indices of the items in a `ConstList` which satisfy a predicate.
+pub struct ConstListPositions<'a, T, F> {
+    /// The items which have not yet been visited, along with their indices.
+    items: Enumerate<ConstListIterator<'a, T>>,
+    /// The predicate which each matching item satisfies.
+    predicate: F,
+}
+
+impl<'a, T, F: Clone> Clone for ConstListPositions<'a, T, F> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self {
+            items: self.items.clone(),
+            predicate: self.predicate.clone(),
+        }
+    }
+}
+
+impl<'a, T, F: FnMut(&T) -> bool> Iterator for ConstListPositions<'a, T, F> {
+    type Item = usize;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        let predicate = &mut self.predicate;
+        self.items
+            .find_map(|(index, value)| predicate(value).then_some(index))
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.items.size_hint().1)
+    }
+}
+
+impl<'a, T, F: FnMut(&T) -> bool> FusedIterator for ConstListPositions<'a, T, F> {}
+
+/// Iterates over the contents of a `ConstList`, allowing the next item to be inspected without advancing.
+pub struct ConstListPeekable<'a, T> {
+    /// The items which have not yet been yielded.
+    items: ConstListIterator<'a, T>,
+}
+
+impl<'a, T> ConstListPeekable<'a, T> {
+    /// Gets the item which will be yielded next, without advancing the iterator.
+    #[inline(always)]
+    pub const fn peek(&self) -> Option<&'a T> {
+        if self.items.remaining == 0 {
+            None
+        } else {
+            self.items.target.pop().0
+        }
+    }
+}
+
+impl<'a, T> Clone for ConstListPeekable<'a, T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for ConstListPeekable<'a, T> {}
+
+impl<'a, T> Iterator for ConstListPeekable<'a, T> {
+    type Item = &'a T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.items.next()
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.items.size_hint()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for ConstListPeekable<'a, T> {}
+
+impl<'a, T> FusedIterator for ConstListPeekable<'a, T> {}
+
+/// Iterates over the contents of a `ConstList`, along with the rest of the list after each item.
+pub struct ConstListWithRest<'a, T> {
+    /// The current list head.
+    target: &'a ConstList<'a, T>,
+}
+
+impl<'a, T> Clone for ConstListWithRest<'a, T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for ConstListWithRest<'a, T> {}
+
+impl<'a, T> Iterator for ConstListWithRest<'a, T> {
+    type Item = (&'a T, &'a ConstList<'a, T>);
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (first, rest) = self.target.split_first()?;
+        self.target = rest;
+        Some((first, rest))
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.target.len(), Some(self.target.len()))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for ConstListWithRest<'a, T> {}
+
+impl<'a, T> FusedIterator for ConstListWithRest<'a, T> {}
+
+/// Formats the node structure of a `ConstList`, as created by [`ConstList::debug_chain`].
+pub struct ConstListDebugChain<'a, T> {
+    /// The list head.
+    target: &'a ConstList<'a, T>,
+}
+
+impl<'a, T> Clone for ConstListDebugChain<'a, T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for ConstListDebugChain<'a, T> {}
+
+impl<'a, T: Debug> Debug for ConstListDebugChain<'a, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str("[")?;
+        let mut list = self.target;
+        while let (Some(value), rest) = list.pop() {
+            write!(f, "{value:?} @ {list:p} -> ")?;
+            list = rest;
+        }
+        write!(f, "[] @ {list:p}]")
+    }
+}
+
+/// An owned list of up to `N` items, with the same ordering as a [`ConstList`].
+/// Unlike a `ConstList`, it does not borrow its predecessors, and so may be
+/// built at runtime and returned from functions.
+#[derive(Copy, Clone)]
+pub struct ConstListBuf<T, const N: usize> {
+    /// The items of this list, stored from tail to head.
+    items: [Option<T>; N],
+    /// The number of items in this list.
+    len: usize,
+}
+
+impl<T, const N: usize> ConstListBuf<T, N> {
+    /// Creates a new, empty list.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self {
+            items: [const { None }; N],
+            len: 0,
+        }
+    }
+
+    /// Creates a list from the items of an iterator, in the same order,
+    /// or returns `None` if the iterator yields more than `N` items.
+    pub fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Option<Self> {
+        let mut result = Self::new();
+        for item in iter {
+            *result.items.get_mut(result.len)? = Some(item);
+            result.len += 1;
+        }
+        result.items[..result.len].reverse();
+        Some(result)
+    }
+
+    /// Gets a reference to the item at the provided index in this list, if any.
+    #[inline(always)]
+    pub const fn get(&self, index: usize) -> Option<&T> {
+        if index < self.len {
+            self.items[self.len - index - 1].as_ref()
+        } else {
+            None
+        }
+    }
+
+    /// Determines the length of this list.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the list is empty.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The maximum number of items which this list may hold.
+    #[inline(always)]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Pushes a new item onto the beginning of this list.
+    /// Panics if the list already holds `N` items.
+    #[inline(always)]
+    pub fn push(&mut self, value: T) {
+        assert!(self.len < N, "ConstListBuf capacity exceeded");
+        self.items[self.len] = Some(value);
+        self.len += 1;
+    }
+
+    /// Removes the first item (if any) from this list.
+    #[inline(always)]
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len > 0 {
+            self.len -= 1;
+            self.items[self.len].take()
+        } else {
+            None
+        }
+    }
+
+    /// Creates an iterator over the contents of the list.
+    #[inline(always)]
+    pub fn iter(&self) -> ConstListBufIterator<'_, T> {
+        ConstListBufIterator {
+            items: self.items[..self.len].iter(),
+        }
+    }
+}
+
+impl<T, const N: usize> Default for ConstListBuf<T, N> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for ConstListBuf<T, N> {
+    #[inline(always)]
+    fn from(value: [T; N]) -> Self {
+        let mut items = value.map(Some);
+        items.reverse();
+        Self { items, len: N }
+    }
+}
+
+impl<T, const N: usize> FromIterator<T> for ConstListBuf<T, N> {
+    /// Creates a list from the items of an iterator, in the same order.
+    /// Panics if the iterator yields more than `N` items.
+    #[inline(always)]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::try_from_iter(iter).expect("ConstListBuf capacity exceeded")
+    }
+}
+
+impl<T: Debug, const N: usize> Debug for ConstListBuf<T, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self).finish()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a ConstListBuf<T, N> {
+    type Item = &'a T;
+
+    type IntoIter = ConstListBufIterator<'a, T>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize, const N: usize> serde::Serialize for ConstListBuf<T, N> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for item in self {
+            seq.serialize_element(item)?;
+        }
+        seq.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>, const N: usize> serde::Deserialize<'de>
+    for ConstListBuf<T, N>
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::{Error, SeqAccess, Visitor};
+
+        /// Deserializes a sequence of at most `N` items.
+        struct ConstListBufVisitor<T, const N: usize> {
+            /// Marks the type of item being deserialized.
+            marker: core::marker::PhantomData<fn() -> T>,
+        }
+
+        impl<'de, T: serde::Deserialize<'de>, const N: usize> Visitor<'de> for ConstListBufVisitor<T, N> {
+            type Value = ConstListBuf<T, N>;
+
+            fn expecting(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+                write!(formatter, "a sequence of at most {N} items")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut result = ConstListBuf::new();
+                while let Some(item) = seq.next_element()? {
+                    if result.len == N {
+                        return Err(A::Error::invalid_length(N + 1, &self));
+                    }
+                    result.items[result.len] = Some(item);
+                    result.len += 1;
+                }
+                result.items[..result.len].reverse();
+                Ok(result)
+            }
+        }
+
+        deserializer.deserialize_seq(ConstListBufVisitor {
+            marker: core::marker::PhantomData,
+        })
+    }
+}
+
+/// Iterates over the contents of a `ConstListBuf`.
+pub struct ConstListBufIterator<'a, T> {
+    /// The remaining items, stored from tail to head.
+    items: core::slice::Iter<'a, Option<T>>,
+}
+
+impl<'a, T> Clone for ConstListBufIterator<'a, T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self {
+            items: self.items.clone(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for ConstListBufIterator<'a, T> {
+    type Item = &'a T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.items.next_back().and_then(Option::as_ref)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.items.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for ConstListBufIterator<'a, T> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.items.next().and_then(Option::as_ref)
+    }
+}
+
+impl<'a, T> ExactSizeIterator for ConstListBufIterator<'a, T> {}
+
+impl<'a, T> FusedIterator for ConstListBufIterator<'a, T> {}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use std::string::{String, ToString};
+
+    #[test]
+    fn fold_empty() {
+        const LIST: ConstList<i32> = const_list![];
+        assert_eq!(LIST.fold(7, |acc, x| acc + *x), 7);
+    }
+
+    #[test]
+    fn fold_single() {
+        const LIST: ConstList<i32> = const_list![5];
+        assert_eq!(LIST.fold(1, |acc, x| acc + *x), 6);
+    }
+
+    #[test]
+    fn fold_multiple() {
+        const LIST: ConstList<i32> = const_list![1, 2, 3];
+        assert_eq!(LIST.fold(0, |acc, x| acc * 10 + *x), 123);
+    }
+
+    #[test]
+    fn iter_stays_exhausted() {
+        const LIST: ConstList<i32> = const_list![1];
+        let mut iter = LIST.iter();
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn iter_clone_is_independent() {
+        const LIST: ConstList<i32> = const_list![1, 2, 3];
+        let mut iter = LIST.iter();
+        iter.next();
+        let mut copy = iter;
+        assert_eq!(copy.next(), Some(&2));
+        assert_eq!(copy.next(), Some(&3));
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next(), Some(&2));
+    }
+
+    #[test]
+    fn iter_from_both_ends() {
+        const LIST: ConstList<i32> = const_list![1, 2, 3, 4, 5];
+        let mut iter = LIST.iter();
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next_back(), Some(&5));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next_back(), Some(&4));
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.next_back(), Some(&3));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn iter_rev() {
+        const LIST: ConstList<i32> = const_list![1, 2, 3];
+        assert!(LIST.iter().rev().eq(&[3, 2, 1]));
+    }
+
+    #[test]
+    fn to_array_exact() {
+        const LIST: ConstList<i32> = const_list![1, 2, 3];
+        const ARRAY: [i32; 3] = LIST.to_array();
+        assert_eq!(ARRAY, [1, 2, 3]);
+        assert!(ConstList::<i32>::new().to_array::<0>().is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "list length does not match array length")]
+    fn to_array_wrong_length() {
+        const LIST: ConstList<i32> = const_list![1, 2, 3];
+        let _ = LIST.to_array::<2>();
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn to_vec_preserves_order() {
+        const LIST: ConstList<i32> = const_list![1, 2, 3];
+        let vec = LIST.to_vec();
+        assert_eq!(vec, [1, 2, 3]);
+        assert!(vec.capacity() >= LIST.len());
+        assert!(ConstList::<i32>::new().to_vec().is_empty());
+    }
+
+    #[test]
+    fn index_in_bounds() {
+        const LIST: ConstList<i32> = const_list![1, 2, 3];
+        assert_eq!(LIST[0], 1);
+        assert_eq!(LIST[2], 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 3 but the index is 3")]
+    fn index_out_of_bounds() {
+        const LIST: ConstList<i32> = const_list![1, 2, 3];
+        let _ = LIST[3];
+    }
+
+    #[test]
+    fn eq_slices_and_arrays() {
+        const LIST: ConstList<i32> = const_list![2, 4, 8];
+        assert_eq!(LIST, [2, 4, 8]);
+        assert_eq!([2, 4, 8], LIST);
+        assert_eq!(LIST, [2, 4, 8][..]);
+        assert_eq!([2, 4, 8][..], LIST);
+        assert_eq!(LIST, &[2, 4, 8][..]);
+        assert_eq!(&[2, 4, 8][..], LIST);
+        assert_ne!(LIST, [2, 4]);
+        assert_ne!(LIST, [2, 4, 9]);
+        assert_ne!([2, 4, 8, 16], LIST);
+        assert_eq!(ConstList::<i32>::new(), []);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serialize_list() {
+        const LIST: ConstList<i32> = const_list![1, 2, 3];
+        assert_eq!(serde_json::to_string(&LIST).unwrap(), "[1,2,3]");
+        assert_eq!(
+            serde_json::to_string(&ConstList::<i32>::new()).unwrap(),
+            "[]"
+        );
+    }
+
+    #[test]
+    fn buf_from_array() {
+        let buf = ConstListBuf::from([1, 2, 3]);
+        assert_eq!(buf.len(), 3);
+        assert_eq!(buf.get(0), Some(&1));
+        assert_eq!(buf.get(2), Some(&3));
+        assert!(buf.iter().eq(&[1, 2, 3]));
+    }
+
+    #[test]
+    fn buf_try_from_iter() {
+        let full = ConstListBuf::<i32, 3>::try_from_iter([1, 2, 3]).unwrap();
+        assert!(full.iter().eq(&[1, 2, 3]));
+
+        let mut partial = ConstListBuf::<i32, 3>::try_from_iter([1, 2]).unwrap();
+        assert_eq!(partial.len(), 2);
+        assert!(partial.iter().eq(&[1, 2]));
+        partial.push(0);
+        assert!(partial.iter().eq(&[0, 1, 2]));
+        assert_eq!(partial.pop(), Some(0));
+
+        assert!(ConstListBuf::<i32, 3>::try_from_iter([1, 2, 3, 4]).is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn buf_deserialize() {
+        let full: ConstListBuf<i32, 3> = serde_json::from_str("[1,2,3]").unwrap();
+        assert!(full.iter().eq(&[1, 2, 3]));
+
+        let partial: ConstListBuf<i32, 3> = serde_json::from_str("[1,2]").unwrap();
+        assert_eq!(partial.len(), 2);
+        assert_eq!(partial.get(0), Some(&1));
+        assert_eq!(partial.get(1), Some(&2));
+
+        assert!(serde_json::from_str::<ConstListBuf<i32, 3>>("[1,2,3,4]").is_err());
+        assert_eq!(serde_json::to_string(&full).unwrap(), "[1,2,3]");
+    }
+
+    #[test]
+    fn push_onto_longer_lived_base() {
+        // Naming the node lifetime used to require borrowing `local` for all of `'static`.
+        const BASE: ConstList<'static, i32> = const_list![2, 3];
+        let local: ConstList<'static, i32> = BASE.push(1);
+        let top = ConstList::<'static, i32>::push(&local, 0);
+        assert_eq!(ConstList::<'static, i32>::pop(&local).0, Some(&1));
+        assert!(<&ConstList<'static, i32>>::into_iter(&local).eq(&[1, 2, 3]));
+        assert_eq!(top, [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn cached_len_matches_walk() {
+        fn walked_len(mut list: &ConstList<i32>) -> usize {
+            let mut len = 0;
+            while let (Some(_), rest) = list.pop() {
+                len += 1;
+                list = rest;
+            }
+            len
+        }
+
+        let base = ConstList::new();
+        let a = base.push(1);
+        let b = a.push(2);
+        let c = b.push(3);
+        let d = b.push(4);
+        let e = d.push(5);
+        for list in [&base, &a, &b, &c, &d, &e] {
+            assert_eq!(list.len(), walked_len(list));
+        }
+        assert_eq!(c.len(), 3);
+        assert_eq!(e.len(), 4);
+    }
+
+    #[test]
+    fn rev_matches_reversed_array() {
+        const LIST: ConstList<i32> = const_list![1, 2, 3, 4];
+        let mut expected = [1, 2, 3, 4];
+        expected.reverse();
+        assert!(LIST.rev().eq(&expected));
+        assert_eq!(ConstList::<i32>::new().rev().next(), None);
+    }
+
+    #[test]
+    fn zip_equal_lengths() {
+        const A: ConstList<i32> = const_list![1, 2, 3];
+        const B: ConstList<char> = const_list!['a', 'b', 'c'];
+        let zip = A.zip(&B);
+        assert_eq!(zip.len(), 3);
+        assert!(zip.eq([(&1, &'a'), (&2, &'b'), (&3, &'c')]));
+    }
+
+    #[test]
+    fn zip_unequal_lengths() {
+        const A: ConstList<i32> = const_list![1, 2, 3];
+        const B: ConstList<char> = const_list!['a'];
+        assert_eq!(A.zip(&B).size_hint(), (1, Some(1)));
+        assert!(A.zip(&B).eq([(&1, &'a')]));
+        assert_eq!(B.zip(&A).count(), 1);
+    }
+
+    #[test]
+    fn flatten_skips_empty_lists() {
+        const A: ConstList<i32> = const_list![1, 2];
+        const EMPTY: ConstList<i32> = const_list![];
+        const B: ConstList<i32> = const_list![3];
+        const OUTER: ConstList<&ConstList<i32>> =
+            const_list![&EMPTY, &A, &EMPTY, &EMPTY, &B, &EMPTY];
+        assert!(OUTER.flatten().eq(&[1, 2, 3]));
+
+        const NONE: ConstList<&ConstList<i32>> = const_list![&EMPTY];
+        assert_eq!(NONE.flatten().next(), None);
+    }
+
+    #[test]
+    fn reduce_cases() {
+        fn max(a: &i32, b: &i32) -> i32 {
+            *a.max(b)
+        }
+
+        assert_eq!(ConstList::<i32>::new().reduce(max), None);
+        const SINGLE: ConstList<i32> = const_list![4];
+        assert_eq!(SINGLE.reduce(max), Some(4));
+        const LIST: ConstList<i32> = const_list![3, 9, 2];
+        assert_eq!(LIST.reduce(max), Some(9));
+        assert_eq!(LIST.reduce(|a, b| a - b), Some(-8));
+    }
+
+    #[test]
+    fn count_by_matches() {
+        const LIST: ConstList<i32> = const_list![1, 2, 3, 4];
+        assert_eq!(LIST.count_by(|x| *x % 2 == 0), 2);
+        assert_eq!(LIST.count_by(|x| *x > 10), 0);
+        assert_eq!(ConstList::<i32>::new().count_by(|_| true), 0);
+    }
+
+    #[test]
+    fn is_sorted_by_cases() {
+        const ASCENDING: ConstList<i32> = const_list![1, 2, 2, 5];
+        const DESCENDING: ConstList<i32> = const_list![5, 3, 1];
+        const UNSORTED: ConstList<i32> = const_list![1, 3, 2];
+        assert!(ASCENDING.is_sorted_by(|a, b| a <= b));
+        assert!(DESCENDING.is_sorted_by(|a, b| a >= b));
+        assert!(!DESCENDING.is_sorted_by(|a, b| a <= b));
+        assert!(!UNSORTED.is_sorted_by(|a, b| a <= b));
+        assert!(ConstList::<i32>::new().is_sorted_by(|_, _| false));
+        assert!(const_list![1].is_sorted_by(|_, _| false));
+    }
+
+    #[test]
+    fn copied_iter_sum() {
+        const LIST: ConstList<i32> = const_list![1, 2, 3];
+        assert_eq!(LIST.copied_iter().sum::<i32>(), 6);
+    }
+
+    #[test]
+    fn windows_lengths() {
+        const LIST: ConstList<i32> = const_list![1, 2, 3, 4];
+        assert_eq!(LIST.windows::<5>().next(), None);
+        assert!(LIST.windows::<4>().eq([[1, 2, 3, 4]]));
+        assert_eq!(LIST.windows::<2>().len(), 3);
+        assert!(LIST.windows::<2>().eq([[1, 2], [2, 3], [3, 4]]));
+    }
+
+    #[test]
+    fn split_at_positions() {
+        const LIST: ConstList<i32> = const_list![1, 2, 3];
+        let (front, back) = LIST.split_at(0);
+        assert_eq!(front.len(), 0);
+        assert!(back.eq(&[1, 2, 3]));
+
+        let (front, back) = LIST.split_at(2);
+        assert!(front.eq(&[1, 2]));
+        assert!(back.eq(&[3]));
+
+        let (front, back) = LIST.split_at(5);
+        assert!(front.eq(&[1, 2, 3]));
+        assert_eq!(back.len(), 0);
+    }
+
+    #[test]
+    fn dedup_iter_cases() {
+        const RUNS: ConstList<i32> = const_list![1, 1, 2, 2, 2, 3, 1];
+        assert!(RUNS.dedup_iter().eq(&[1, 2, 3, 1]));
+        const DISTINCT: ConstList<i32> = const_list![1, 2, 3];
+        assert!(DISTINCT.dedup_iter().eq(&[1, 2, 3]));
+        const SAME: ConstList<i32> = const_list![7, 7, 7];
+        assert!(SAME.dedup_iter().eq(&[7]));
+        assert_eq!(ConstList::<i32>::new().dedup_iter().next(), None);
+    }
+
+    #[test]
+    fn intersperse_cases() {
+        assert_eq!(ConstList::<i32>::new().intersperse(&0).next(), None);
+        const SINGLE: ConstList<i32> = const_list![1];
+        assert!(SINGLE.intersperse(&0).eq(&[1]));
+        const LIST: ConstList<i32> = const_list![1, 2, 3];
+        let iter = LIST.intersperse(&0);
+        assert_eq!(iter.len(), 5);
+        assert!(iter.eq(&[1, 0, 2, 0, 3]));
+    }
+
+    #[test]
+    fn indices_of_by_cases() {
+        const LIST: ConstList<i32> = const_list![1, 2, 3, 4, 6];
+        assert_eq!(LIST.indices_of_by::<4>(|x| *x > 10), ([0; 4], 0));
+        assert_eq!(LIST.indices_of_by::<4>(|x| *x % 2 == 1), ([0, 2, 0, 0], 2));
+        assert_eq!(LIST.indices_of_by::<2>(|x| *x % 2 == 0), ([1, 3], 2));
+    }
+
+    #[test]
+    fn try_fold_cases() {
+        const LIST: ConstList<u8> = const_list![100, 100, 100];
+        assert_eq!(
+            LIST.try_fold(0u8, |acc, x| acc.checked_add(*x / 2)),
+            Some(150)
+        );
+        assert_eq!(LIST.try_fold(0u8, |acc, x| acc.checked_add(*x)), None);
+        assert_eq!(ConstList::<u8>::new().try_fold(7, |_, _| None), Some(7));
+    }
+
+    #[test]
+    fn partition_cases() {
+        const LIST: ConstList<i32> = const_list![1, 2, 3, 4];
+        assert_eq!(LIST.partition::<4>(|_| true), ([1, 2, 3, 4], 4, [0; 4], 0));
+        assert_eq!(LIST.partition::<4>(|_| false), ([0; 4], 0, [1, 2, 3, 4], 4));
+        assert_eq!(
+            LIST.partition::<4>(|x| *x % 2 == 0),
+            ([2, 4, 0, 0], 2, [1, 3, 0, 0], 2)
+        );
+        assert_eq!(LIST.partition::<1>(|x| *x % 2 == 0), ([2], 1, [1], 1));
+    }
+
+    #[test]
+    fn get_back_cases() {
+        const LIST: ConstList<i32> = const_list![1, 2, 3];
+        assert_eq!(LIST.get_back(0), Some(&3));
+        assert_eq!(LIST.get_back(1), Some(&2));
+        assert_eq!(LIST.get_back(3), None);
+        assert_eq!(ConstList::<i32>::new().get_back(0), None);
+    }
+
+    #[test]
+    fn map_into_cases() {
+        const LIST: ConstList<i32> = const_list![1, 2, 3];
+        assert_eq!(LIST.map_into::<_, 3>(|x| x * 2), ([2, 4, 6], 3));
+        assert_eq!(LIST.map_into::<_, 2>(|x| x * 2), ([2, 4], 2));
+        assert_eq!(LIST.map_into::<_, 4>(|x| x * 2), ([2, 4, 6, 0], 3));
+    }
+
+    #[test]
+    fn filter_into_cases() {
+        const LIST: ConstList<i32> = const_list![1, 2, 3, 4, 6];
+        assert_eq!(LIST.filter_into::<2>(|x| *x > 10), ([0, 0], 0));
+        assert_eq!(LIST.filter_into::<3>(|x| *x % 2 == 1), ([1, 3, 0], 2));
+        assert_eq!(LIST.filter_into::<2>(|x| *x % 2 == 0), ([2, 4], 2));
+    }
+
+    #[test]
+    fn eq_by_cases() {
+        const A: ConstList<i32> = const_list![1, 2, 3];
+        const B: ConstList<i32> = const_list![1, 2, 3];
+        const SHORT: ConstList<i32> = const_list![1, 2];
+        const OTHER: ConstList<i32> = const_list![1, 2, 4];
+        assert!(A.eq_by(&B, |a, b| a == b));
+        assert!(!A.eq_by(&SHORT, |a, b| a == b));
+        assert!(!A.eq_by(&OTHER, |a, b| a == b));
+        assert!(ConstList::<i32>::new().eq_by(&ConstList::new(), |_, _| false));
+    }
+
+    #[test]
+    fn group_runs_lengths() {
+        const LIST: ConstList<i32> = const_list![1, 1, 2, 3, 3, 3, 4];
+        assert!(LIST.group_runs().eq([(&1, 2), (&2, 1), (&3, 3), (&4, 1)]));
+        assert_eq!(ConstList::<i32>::new().group_runs().next(), None);
+    }
+
+    #[test]
+    fn long_list_in_const() {
+        const DATA: [usize; 32] = {
+            let mut data = [0; 32];
+            let mut index = 0;
+            while index < 32 {
+                data[index] = index;
+                index += 1;
+            }
+            data
+        };
+
+        // 320 items, well past the 128-frame limit which recursive traversals would hit.
+        const L1: ConstList<usize> = push_array!(ConstList::new(), DATA);
+        const L2: ConstList<usize> = push_array!(L1, DATA);
+        const L3: ConstList<usize> = push_array!(L2, DATA);
+        const L4: ConstList<usize> = push_array!(L3, DATA);
+        const L5: ConstList<usize> = push_array!(L4, DATA);
+        const L6: ConstList<usize> = push_array!(L5, DATA);
+        const L7: ConstList<usize> = push_array!(L6, DATA);
+        const L8: ConstList<usize> = push_array!(L7, DATA);
+        const L9: ConstList<usize> = push_array!(L8, DATA);
+        const L10: ConstList<usize> = push_array!(L9, DATA);
+
+        const LEN: usize = L10.len();
+        const LAST: usize = *L10.get(319).unwrap();
+        const COUNT: usize = L10.count();
+        assert_eq!(LEN, 320);
+        assert_eq!(LAST, 0);
+        assert_eq!(COUNT, 320);
+        assert_eq!(L10.get(320), None);
+        assert_eq!(L10.get(33), Some(&30));
+    }
+
+    #[test]
+    fn first_and_last_chunks() {
+        const LIST: ConstList<i32> = const_list![1, 2, 3];
+        const FIRST: Option<[i32; 2]> = LIST.first_chunk();
+        assert_eq!(FIRST, Some([1, 2]));
+        assert_eq!(LIST.last_chunk::<2>(), Some([2, 3]));
+        assert_eq!(LIST.first_chunk::<3>(), Some([1, 2, 3]));
+        assert_eq!(LIST.last_chunk::<3>(), Some([1, 2, 3]));
+        assert_eq!(LIST.first_chunk::<4>(), None);
+        assert_eq!(LIST.last_chunk::<4>(), None);
+        assert_eq!(ConstList::<i32>::new().first_chunk::<0>(), Some([]));
+        assert_eq!(ConstList::<i32>::new().last_chunk::<1>(), None);
+    }
+
+    #[test]
+    fn position_back_by_finds_last() {
+        const LIST: ConstList<i32> = const_list![2, 1, 2, 3, 2, 5];
+        assert_eq!(LIST.position_back_by(|x| *x == 2), Some(4));
+        assert_eq!(LIST.position_back_by(|x| *x == 9), None);
+        assert_eq!(ConstList::<i32>::new().position_back_by(|_| true), None);
+    }
+
+    #[test]
+    fn concat_into_cases() {
+        const A: ConstList<i32> = const_list![1, 2];
+        const EMPTY: ConstList<i32> = const_list![];
+        const B: ConstList<i32> = const_list![3, 4];
+        let mut out = [0; 5];
+        assert_eq!(ConstList::concat_into(&[&A, &EMPTY, &B], &mut out), 4);
+        assert_eq!(out, [1, 2, 3, 4, 0]);
+
+        let mut short = [0; 3];
+        assert_eq!(ConstList::concat_into(&[&A, &EMPTY, &B], &mut short), 3);
+        assert_eq!(short, [1, 2, 3]);
+    }
+
+    #[test]
+    fn find_map_by_cases() {
+        const LIST: ConstList<i32> = const_list![1, 4, 6];
+        assert_eq!(
+            LIST.find_map_by(|x| (*x % 2 == 0).then_some(*x * 10)),
+            Some(40)
+        );
+        assert_eq!(LIST.find_map_by(|x| (*x > 10).then_some(*x)), None);
+        assert_eq!(ConstList::<i32>::new().find_map_by(|x| Some(*x)), None);
+    }
+
+    #[test]
+    fn truncate_iter_cases() {
+        const LIST: ConstList<i32> = const_list![1, 2, 3];
+        let iter = LIST.truncate_iter(2);
+        assert_eq!(iter.len(), 2);
+        assert!(iter.eq(&[1, 2]));
+        assert_eq!(LIST.truncate_iter(0).next(), None);
+        assert_eq!(LIST.truncate_iter(5).len(), 3);
+        assert!(LIST.truncate_iter(5).eq(&[1, 2, 3]));
+    }
+
+    #[test]
+    fn skip_cases() {
+        const LIST: ConstList<i32> = const_list![1, 2, 3];
+        assert_eq!(*LIST.skip(0), [1, 2, 3]);
+        assert_eq!(*LIST.skip(2), [3]);
+        assert!(LIST.skip(3).is_empty());
+        assert!(LIST.skip(10).is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn to_vec_rev_order() {
+        let base = ConstList::new();
+        let a = base.push(1);
+        let b = a.push(2);
+        let c = b.push(3);
+        assert_eq!(c.to_vec_rev(), [1, 2, 3]);
+        assert!(base.to_vec_rev().is_empty());
+    }
+
+    #[test]
+    fn retain_into_cases() {
+        const LIST: ConstList<i32> = const_list![5, 6, 7, 8];
+        assert_eq!(LIST.retain_into::<2>(|x| *x > 10), ([(0, 0); 2], 0));
+        assert_eq!(
+            LIST.retain_into::<3>(|x| *x % 2 == 0),
+            ([(1, 6), (3, 8), (0, 0)], 2)
+        );
+        assert_eq!(LIST.retain_into::<1>(|x| *x % 2 == 0), ([(1, 6)], 1));
+    }
+
+    #[test]
+    fn count_matches_len() {
+        const LIST: ConstList<i32> = const_list![1, 2, 3];
+        let empty = ConstList::new();
+        let a = empty.push(1);
+        let b = a.push(2);
+        let c = a.push(3);
+        assert_eq!(LIST.count(), LIST.len());
+        for list in [&empty, &a, &b, &c, LIST.skip(1)] {
+            assert_eq!(list.count(), list.len());
+        }
+    }
+
+    #[test]
+    fn iter_while_cases() {
+        const LIST: ConstList<i32> = const_list![2, 4, 5, 6];
+        assert!(LIST.iter_while(|x| *x % 2 == 0).eq(&[2, 4]));
+        assert_eq!(LIST.iter_while(|x| *x > 2).next(), None);
+        assert!(LIST.iter_while(|_| true).eq(&[2, 4, 5, 6]));
+
+        let mut iter = LIST.iter_while(|x| *x % 2 == 0);
+        iter.by_ref().for_each(drop);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn max_and_min_items() {
+        const SIGNED: ConstList<i8> = const_list![3, -7, 12, 0];
+        assert_eq!(SIGNED.max_item(), Some(&12));
+        assert_eq!(SIGNED.min_item(), Some(&-7));
+        const UNSIGNED: ConstList<u64> = const_list![5, 5, 1];
+        assert_eq!(UNSIGNED.max_item(), Some(&5));
+        assert_eq!(UNSIGNED.min_item(), Some(&1));
+        const CHARS: ConstList<char> = const_list!['q', 'a', 'z'];
+        assert_eq!(CHARS.max_item(), Some(&'z'));
+        assert_eq!(ConstList::<i32>::new().max_item(), None);
+        assert_eq!(ConstList::<i32>::new().min_item(), None);
+    }
+
+    #[test]
+    fn split_first_cases() {
+        const LIST: ConstList<i32> = const_list![1, 2];
+        let (first, rest) = LIST.split_first().unwrap();
+        assert_eq!(*first, 1);
+        assert_eq!(*rest, [2]);
+        assert!(ConstList::<i32>::new().split_first().is_none());
+    }
+
+    #[test]
+    fn find_indexed_cases() {
+        const LIST: ConstList<i32> = const_list![1, 4, 6];
+        assert_eq!(LIST.find_indexed(|x| *x % 2 == 0), Some((1, &4)));
+        assert_eq!(LIST.position_by(|x| *x % 2 == 0), Some(1));
+        assert_eq!(LIST.find_indexed(|x| *x > 10), None);
+        assert_eq!(ConstList::<i32>::new().find_indexed(|_| true), None);
+    }
+
+    #[test]
+    fn dedup_all_into_keeps_first_occurrences() {
+        const LIST: ConstList<i32> = const_list![1, 2, 1, 3, 2];
+        let mut out = [0; 5];
+        assert_eq!(LIST.dedup_all_into(&mut out), 3);
+        assert_eq!(out[..3], [1, 2, 3]);
+
+        let mut short = [0; 2];
+        assert_eq!(LIST.dedup_all_into(&mut short), 2);
+        assert_eq!(short, [1, 2]);
+    }
+
+    #[test]
+    fn iter_from_cases() {
+        const LIST: ConstList<i32> = const_list![1, 2, 3];
+        assert!(LIST.iter_from(0).eq(&[1, 2, 3]));
+        let iter = LIST.iter_from(1);
+        assert_eq!(iter.len(), 2);
+        assert!(iter.eq(&[2, 3]));
+        assert_eq!(LIST.iter_from(3).next(), None);
+        assert_eq!(LIST.iter_from(9).len(), 0);
+    }
+
+    #[test]
+    fn cmp_by_cases() {
+        const A: ConstList<i32> = const_list![1, 2, 3];
+        const PREFIX: ConstList<i32> = const_list![1, 2];
+        const GREATER: ConstList<i32> = const_list![1, 3];
+        assert_eq!(A.cmp_by(&A, i32::cmp), Ordering::Equal);
+        assert_eq!(PREFIX.cmp_by(&A, i32::cmp), Ordering::Less);
+        assert_eq!(A.cmp_by(&PREFIX, i32::cmp), Ordering::Greater);
+        assert_eq!(A.cmp_by(&GREATER, i32::cmp), Ordering::Less);
+        assert_eq!(GREATER.cmp_by(&A, i32::cmp), Ordering::Greater);
+        let empty = ConstList::<i32>::new();
+        assert_eq!(empty.cmp_by(&ConstList::new(), i32::cmp), Ordering::Equal);
+    }
+
+    #[test]
+    fn nth_back_cases() {
+        const LIST: ConstList<i32> = const_list![1, 2, 3];
+        assert_eq!(LIST.nth_back(0), Some(&3));
+        assert_eq!(LIST.nth_back(1), Some(&2));
+        assert_eq!(LIST.nth_back(3), None);
+        assert_eq!(ConstList::<i32>::new().nth_back(0), None);
+    }
+
+    #[test]
+    fn try_get_cases() {
+        const LIST: ConstList<i32> = const_list![1, 2, 3];
+        assert_eq!(LIST.try_get(1), Ok(&2));
+        assert_eq!(
+            LIST.try_get(5),
+            Err(ConstListError::IndexOutOfBounds { index: 5, len: 3 })
+        );
+    }
+
+    #[test]
+    fn prefix_sums_into_cases() {
+        const LIST: ConstList<i32> = const_list![1, 2, 3];
+        let mut out = [0; 4];
+        assert_eq!(LIST.prefix_sums_into(&mut out), 3);
+        assert_eq!(out, [1, 3, 6, 0]);
+
+        let mut short = [0; 2];
+        assert_eq!(LIST.prefix_sums_into(&mut short), 2);
+        assert_eq!(short, [1, 3]);
+
+        assert_eq!(ConstList::<i32>::new().prefix_sums_into(&mut out), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn rev_iter_order() {
+        const LIST: ConstList<i32> = const_list![1, 2, 3];
+        assert!(LIST.rev_iter().eq([3, 2, 1]));
+    }
+
+    #[test]
+    fn contains_cases() {
+        const LIST: ConstList<i32> = const_list![1, 2, 3];
+        assert!(LIST.contains(&2));
+        assert!(!LIST.contains(&4));
+        assert!(!ConstList::<i32>::new().contains(&1));
+
+        let base = ConstList::new();
+        let a = base.push("x".to_string());
+        let b = a.push("y".to_string());
+        assert!(b.contains(&"x".to_string()));
+        assert!(!b.contains(&String::new()));
+    }
+
+    #[test]
+    fn pairs_cases() {
+        assert_eq!(ConstList::<i32>::new().pairs().next(), None);
+        const SINGLE: ConstList<i32> = const_list![1];
+        assert_eq!(SINGLE.pairs().next(), None);
+        const LIST: ConstList<i32> = const_list![1, 2, 3, 4];
+        assert!(LIST.pairs().eq([(&1, &2), (&2, &3), (&3, &4)]));
+
+        let base = ConstList::new();
+        let a = base.push("b".to_string());
+        let b = a.push("a".to_string());
+        assert!(b.pairs().all(|(x, y)| x < y));
+    }
+
+    #[test]
+    fn for_each_indexed_accumulates() {
+        use core::fmt::Write;
+
+        const LIST: ConstList<i32> = const_list![4, 5, 6];
+        let mut out = String::new();
+        LIST.for_each_indexed(|index, value| write!(out, "{index}:{value} ").unwrap());
+        assert_eq!(out, "0:4 1:5 2:6 ");
+    }
+
+    #[test]
+    fn equals_array_cases() {
+        const LIST: ConstList<i32> = const_list![2, 4, 8];
+        assert!(LIST.equals_array(&[2, 4, 8], |a, b| a == b));
+        assert!(!LIST.equals_array(&[2, 4], |a, b| a == b));
+        assert!(!LIST.equals_array(&[2, 4, 8, 16], |a, b| a == b));
+        assert!(!LIST.equals_array(&[2, 4, 9], |a, b| a == b));
+        assert!(ConstList::<i32>::new().equals_array(&[], |_, _| false));
+    }
+
+    #[test]
+    fn head_tail_cases() {
+        assert_eq!(ConstList::<i32>::new().head_tail(), None);
+        const SINGLE: ConstList<i32> = const_list![1];
+        assert_eq!(SINGLE.head_tail(), Some((&1, 0)));
+        const LIST: ConstList<i32> = const_list![1, 2, 3];
+        const HEAD_TAIL: Option<(&i32, usize)> = LIST.head_tail();
+        assert_eq!(HEAD_TAIL, Some((&1, 2)));
+    }
+
+    #[test]
+    fn zip_into_cases() {
+        const A: ConstList<i32> = const_list![1, 2, 3];
+        const B: ConstList<char> = const_list!['a', 'b', 'c'];
+        const SHORT: ConstList<char> = const_list!['a'];
+        let mut out = [(0, ' '); 4];
+        assert_eq!(A.zip_into(&B, &mut out), 3);
+        assert_eq!(out[..3], [(1, 'a'), (2, 'b'), (3, 'c')]);
+        assert_eq!(A.zip_into(&SHORT, &mut out), 1);
+
+        let mut small = [(0, ' '); 2];
+        assert_eq!(A.zip_into(&B, &mut small), 2);
+        assert_eq!(small, [(1, 'a'), (2, 'b')]);
+    }
+
+    #[test]
+    fn prefix_and_suffix_cases() {
+        const LIST: ConstList<i32> = const_list![1, 2, 3];
+        const PREFIX: ConstList<i32> = const_list![1, 2];
+        const SUFFIX: ConstList<i32> = const_list![2, 3];
+        const LONGER: ConstList<i32> = const_list![0, 1, 2, 3];
+        let empty = ConstList::new();
+        assert!(PREFIX.is_prefix_of(&LIST));
+        assert!(!SUFFIX.is_prefix_of(&LIST));
+        assert!(SUFFIX.is_suffix_of(&LIST));
+        assert!(!PREFIX.is_suffix_of(&LIST));
+        assert!(LIST.is_suffix_of(&LIST));
+        assert!(!LONGER.is_suffix_of(&LIST));
+        assert!(!LONGER.is_prefix_of(&LIST));
+        assert!(empty.is_prefix_of(&LIST));
+        assert!(empty.is_suffix_of(&LIST));
+    }
+
+    #[test]
+    fn sum_cases() {
+        const INTS: ConstList<i32> = const_list![1, -2, 10];
+        assert_eq!(INTS.sum(), 9);
+        const FLOATS: ConstList<f64> = const_list![0.5, 1.25, 2.0];
+        assert_eq!(FLOATS.sum(), 3.75);
+        assert_eq!(ConstList::<u8>::new().sum(), 0);
+        assert_eq!(ConstList::<f32>::new().sum(), 0.0);
+    }
+
+    #[test]
+    fn sum_by_cases() {
+        const NAMES: ConstList<&str> = const_list!["a", "bcd", "ef"];
+        assert_eq!(NAMES.sum_by(|name| name.len() as i64), 6);
+        assert_eq!(ConstList::<&str>::new().sum_by(|_| 1), 0);
+        const LARGE: ConstList<i64> = const_list![i64::MAX, 1];
+        assert_eq!(LARGE.sum_by(|x| *x), i64::MIN);
+    }
+
+    #[test]
+    fn positions_cases() {
+        use std::vec::Vec;
+
+        const LIST: ConstList<i32> = const_list![1, 2, 3, 4, 6];
+        assert_eq!(
+            LIST.positions(|x| *x % 2 == 0).collect::<Vec<_>>(),
+            [1, 3, 4]
+        );
+        assert_eq!(LIST.positions(|x| *x > 10).next(), None);
+
+        let mut visited = 0;
+        assert_eq!(
+            LIST.positions(|x| {
+                visited += 1;
+                *x % 2 == 0
+            })
+            .next(),
+            Some(1)
+        );
+        assert_eq!(visited, 2);
+    }
+
+    #[test]
+    fn buf_collect() {
+        let full: ConstListBuf<i32, 3> = (1..=3).collect();
+        assert!(full.iter().eq(&[1, 2, 3]));
+        let partial: ConstListBuf<i32, 3> = (1..=2).collect();
+        assert!(partial.iter().eq(&[1, 2]));
+        let empty: ConstListBuf<i32, 3> = core::iter::empty().collect();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "ConstListBuf capacity exceeded")]
+    fn buf_collect_overflow() {
+        let _: ConstListBuf<i32, 3> = (1..=4).collect();
+    }
+
+    #[test]
+    fn peekable_cases() {
+        const LIST: ConstList<i32> = const_list![1, 2];
+        let mut iter = LIST.peekable();
+        assert_eq!(iter.peek(), Some(&1));
+        assert_eq!(iter.peek(), Some(&1));
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.peek(), Some(&2));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.peek(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn len_predicates_match_len() {
+        const LIST: ConstList<i32> = const_list![1, 2, 3];
+        let empty = ConstList::<i32>::new();
+        for n in 0..5 {
+            assert_eq!(LIST.len_at_least(n), LIST.len() >= n);
+            assert_eq!(LIST.len_exactly(n), LIST.len() == n);
+            assert_eq!(empty.len_at_least(n), n == 0);
+            assert_eq!(empty.len_exactly(n), n == 0);
+        }
+    }
+
+    #[test]
+    fn unzip_into_cases() {
+        const PAIRS: ConstList<(i32, char)> = const_list![(1, 'a'), (2, 'b'), (3, 'c')];
+        assert_eq!(
+            PAIRS.unzip_into::<_, _, 3>(|pair| *pair),
+            ([1, 2, 3], ['a', 'b', 'c'], 3)
+        );
+        assert_eq!(
+            PAIRS.unzip_into::<_, _, 2>(|pair| *pair),
+            ([1, 2], ['a', 'b'], 2)
+        );
+        assert_eq!(
+            PAIRS.unzip_into::<_, _, 4>(|pair| *pair),
+            ([1, 2, 3, 0], ['a', 'b', 'c', '\0'], 3)
+        );
+    }
+
+    #[test]
+    fn cycle_into_cases() {
+        const LIST: ConstList<i32> = const_list![1, 2, 3];
+        let mut short = [0; 2];
+        assert_eq!(LIST.cycle_into(&mut short), 2);
+        assert_eq!(short, [1, 2]);
+
+        let mut long = [0; 7];
+        assert_eq!(LIST.cycle_into(&mut long), 7);
+        assert_eq!(long, [1, 2, 3, 1, 2, 3, 1]);
+
+        let mut untouched = [9; 3];
+        assert_eq!(ConstList::new().cycle_into(&mut untouched), 0);
+        assert_eq!(untouched, [9; 3]);
+    }
+
+    #[test]
+    fn fold_indexed_cases() {
+        const LIST: ConstList<i32> = const_list![5, 6, 7];
+        assert_eq!(
+            LIST.fold_indexed(0, |acc, index, value| acc + index as i32 * value),
+            20
+        );
+        assert_eq!(
+            LIST.fold_indexed(0, |expected, index, _| {
+                assert_eq!(index, expected);
+                expected + 1
+            }),
+            3
+        );
+        assert_eq!(ConstList::<i32>::new().fold_indexed(4, |_, _, _| 0), 4);
+    }
+
+    #[test]
+    fn iter_cloned_cases() {
+        use std::vec::Vec;
+
+        #[derive(Clone, Debug, PartialEq)]
+        struct Name(String);
+
+        const WORDS: ConstList<&str> = const_list!["a", "b"];
+        let owned = WORDS.iter_cloned().map(String::from).collect::<Vec<_>>();
+        assert_eq!(owned, ["a", "b"]);
+
+        let base = ConstList::new();
+        let a = base.push(Name("y".to_string()));
+        let b = a.push(Name("x".to_string()));
+        assert!(b
+            .iter_cloned()
+            .eq([Name("x".to_string()), Name("y".to_string())]));
+        assert_eq!(base.iter_cloned().next(), None);
+    }
+
+    #[test]
+    fn remove_into_cases() {
+        const LIST: ConstList<i32> = const_list![1, 2, 3];
+        let mut out = [0; 3];
+        assert_eq!(LIST.remove_into(0, &mut out), Some((1, 2)));
+        assert_eq!(out[..2], [2, 3]);
+        assert_eq!(LIST.remove_into(1, &mut out), Some((2, 2)));
+        assert_eq!(out[..2], [1, 3]);
+        assert_eq!(LIST.remove_into(2, &mut out), Some((3, 2)));
+        assert_eq!(out[..2], [1, 2]);
+        assert_eq!(LIST.remove_into(3, &mut out), None);
+    }
+
+    #[test]
+    fn insert_into_cases() {
+        const LIST: ConstList<i32> = const_list![1, 2, 3];
+        let mut out = [0; 4];
+        assert_eq!(LIST.insert_into(0, 9, &mut out), Some(4));
+        assert_eq!(out, [9, 1, 2, 3]);
+        assert_eq!(LIST.insert_into(1, 9, &mut out), Some(4));
+        assert_eq!(out, [1, 9, 2, 3]);
+        assert_eq!(LIST.insert_into(3, 9, &mut out), Some(4));
+        assert_eq!(out, [1, 2, 3, 9]);
+        assert_eq!(LIST.insert_into(4, 9, &mut out), None);
+    }
+
+    #[test]
+    fn eq_with_shared_and_separate_nodes() {
+        let base = ConstList::new();
+        let a = base.push(2.0);
+        let list = a.push(1.0);
+        let other_base = ConstList::new();
+        let b = other_base.push(2.0);
+        let other = b.push(1.0);
+        assert_eq!(list, list);
+        assert_eq!(list, other);
+        assert!(list.eq_by(&list, |a, b| a == b));
+        assert!(list.eq_by(&other, |a, b| a == b));
+
+        // Shared nodes are still compared, so non-reflexive items are never equal to themselves.
+        let nan = base.push(f64::NAN);
+        let copy = nan;
+        assert_ne!(nan, nan);
+        assert_ne!(nan, copy);
+        assert_ne!(nan, [f64::NAN]);
+        assert_eq!(nan.partial_cmp(&nan), None);
+        assert!(!nan.eq_by(&nan, |_, _| false));
+        assert!(!list.eq_by(&list, |_, _| false));
+    }
+
+    #[test]
+    fn shared_tail_len_cases() {
+        let base = ConstList::new();
+        let a = base.push(1);
+        let b = a.push(2);
+        let left = b.push(3);
+        let right = b.push(4);
+        let longer = right.push(5);
+        assert_eq!(left.shared_tail_len(&right), 2);
+        assert_eq!(left.shared_tail_len(&longer), 2);
+        assert_eq!(longer.shared_tail_len(&left), 2);
+        assert_eq!(left.shared_tail_len(&left), 3);
+
+        let other_base = ConstList::new();
+        let c = other_base.push(1);
+        let d = c.push(2);
+        let separate = d.push(3);
+        assert_eq!(separate, left);
+        assert_eq!(left.shared_tail_len(&separate), 0);
+    }
+
+    #[test]
+    fn iter_with_rest_cases() {
+        const LIST: ConstList<i32> = const_list![1, 2, 3];
+        let mut iter = LIST.iter_with_rest();
+        assert_eq!(iter.len(), 3);
+        for (expected, (value, rest)) in [1, 2, 3].iter().zip(&mut iter) {
+            assert_eq!(value, expected);
+            assert_eq!(rest.len(), 3 - *expected as usize);
+        }
+        assert!(iter.next().is_none());
+        assert!(ConstList::<i32>::new().iter_with_rest().next().is_none());
+    }
+
+    #[test]
+    fn try_push_up_to_cap() {
+        const fn fill(list: &ConstList<i32>, value: i32) -> Option<usize> {
+            match list.try_push(value, 2) {
+                Ok(next) => fill(&next, value + 1),
+                Err(rejected) => Some(rejected as usize),
+            }
+        }
+
+        const EMPTY: ConstList<i32> = ConstList::new();
+        const ONE: ConstList<i32> = match EMPTY.try_push(1, 2) {
+            Ok(list) => list,
+            Err(_) => panic!(),
+        };
+        const TWO: ConstList<i32> = match ONE.try_push(2, 2) {
+            Ok(list) => list,
+            Err(_) => panic!(),
+        };
+        const REJECTED: Result<ConstList<i32>, i32> = TWO.try_push(3, 2);
+        const FIRST_REJECTED: Option<usize> = fill(&EMPTY, 0);
+        assert_eq!(TWO, [2, 1]);
+        assert_eq!(REJECTED, Err(3));
+        assert_eq!(FIRST_REJECTED, Some(2));
+    }
+
+    #[test]
+    fn flat_map_into_cases() {
+        const LIST: ConstList<usize> = const_list![0, 1, 2, 3];
+        let mut out = [0; 8];
+        assert_eq!(LIST.flat_map_into(|x| ([*x; 3], *x), &mut out), 6);
+        assert_eq!(out[..6], [1, 2, 2, 3, 3, 3]);
+
+        let mut short = [0; 4];
+        assert_eq!(LIST.flat_map_into(|x| ([*x; 3], *x), &mut short), 4);
+        assert_eq!(short, [1, 2, 2, 3]);
+
+        assert_eq!(LIST.flat_map_into(|x| ([*x; 2], 5), &mut out), 8);
+        assert_eq!(out, [0, 0, 1, 1, 2, 2, 3, 3]);
+    }
+
+    #[test]
+    fn debug_chain_marks_each_node() {
+        use std::format;
+
+        let base = ConstList::new();
+        let a = base.push(1);
+        let b = a.push(2);
+        let chain = format!("{:?}", b.debug_chain());
+        assert!(chain.starts_with("[2 @ 0x"));
+        assert!(chain.ends_with(']'));
+        assert_eq!(chain.matches(" @ ").count(), 3);
+        assert_eq!(chain.matches(" -> ").count(), 2);
+
+        let a_chain = format!("{:?}", a.debug_chain());
+        assert!(chain.ends_with(&a_chain[1..]));
+    }
+
+    #[test]
+    fn ordering() {
+        const SHORT: ConstList<i32> = const_list![1, 2];
+        const LONG: ConstList<i32> = const_list![1, 2, 3];
+        const DIFFERENT: ConstList<i32> = const_list![1, 3];
+        let empty = ConstList::<i32>::new();
+        assert!(SHORT < LONG);
+        assert_eq!(SHORT.cmp(&LONG), Ordering::Less);
+        assert!(empty < SHORT);
+        assert_eq!(empty.cmp(&ConstList::new()), Ordering::Equal);
+        assert!(DIFFERENT > LONG);
+        assert_eq!(DIFFERENT.partial_cmp(&LONG), Some(Ordering::Greater));
+        assert_eq!(LONG.max(DIFFERENT), DIFFERENT);
+    }
+
+    #[test]
+    fn hashing() {
+        use std::hash::DefaultHasher;
+
+        fn hash(list: &ConstList<i32>) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            list.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        const ONE: ConstList<i32> = const_list![1];
+        const TWO: ConstList<i32> = const_list![1, 1];
+        let base = ConstList::new();
+        let separate = base.push(1);
+        assert_eq!(hash(&ONE), hash(&separate));
+        assert_ne!(hash(&ONE), hash(&TWO));
+    }
+
+    #[test]
+    fn debug_and_display_follow_iteration_order() {
+        use std::format;
+
+        const SINGLE: ConstList<i32> = const_list![1];
+        const LIST: ConstList<i32> = const_list![1, 2, 3];
+        let empty = ConstList::<i32>::new();
+        assert_eq!(format!("{empty:?}"), "[]");
+        assert_eq!(format!("{empty}"), "[]");
+        assert_eq!(format!("{SINGLE:?}"), "[1]");
+        assert_eq!(format!("{SINGLE}"), "[1]");
+        assert_eq!(format!("{LIST:?}"), "[1, 2, 3]");
+        assert_eq!(format!("{LIST}"), "[1, 2, 3]");
+
+        const WORDS: ConstList<&str> = const_list!["a", "b"];
+        assert_eq!(format!("{WORDS:?}"), "[\"a\", \"b\"]");
+        assert_eq!(format!("{WORDS}"), "[a, b]");
+    }
+
+    #[test]
+    fn nth_resumes_after_item() {
+        const LIST: ConstList<i32> = const_list![1, 2, 3, 4];
+        let (value, rest) = LIST.nth(1);
+        assert_eq!(value, Some(&2));
+        assert_eq!(rest.len(), 2);
+        assert!(rest.eq(&[3, 4]));
+
+        let (value, rest) = LIST.nth(3);
+        assert_eq!(value, Some(&4));
+        assert_eq!(rest.len(), 0);
+
+        let (value, mut rest) = LIST.nth(4);
+        assert_eq!(value, None);
+        assert_eq!(rest.next(), None);
+        assert_eq!(LIST.nth(10).1.len(), 0);
+    }
+
+    #[test]
+    fn split_last_cases() {
+        const LIST: ConstList<i32> = const_list![1, 2, 3];
+        let (last, init) = LIST.split_last().unwrap();
+        assert_eq!(*last, 3);
+        assert_eq!(init.len(), LIST.len() - 1);
+        assert!(init.eq(&[1, 2]));
+
+        const SINGLE: ConstList<i32> = const_list![1];
+        let (last, mut init) = SINGLE.split_last().unwrap();
+        assert_eq!(*last, 1);
+        assert_eq!(init.next(), None);
+
+        assert!(ConstList::<i32>::new().split_last().is_none());
+    }
+
+    #[test]
+    fn reverse_into_cases() {
+        const LIST: ConstList<i32> = const_list![2, 4, 8];
+        let mut exact = [0; 3];
+        assert_eq!(LIST.reverse_into(&mut exact), 3);
+        assert_eq!(exact, [8, 4, 2]);
+
+        let mut short = [0; 2];
+        assert_eq!(LIST.reverse_into(&mut short), 2);
+        assert_eq!(short, [8, 4]);
+
+        let mut long = [0; 5];
+        assert_eq!(LIST.reverse_into(&mut long), 3);
+        assert_eq!(long, [8, 4, 2, 0, 0]);
+
+        assert_eq!(ConstList::new().reverse_into(&mut long), 0);
+    }
+
+    #[test]
+    fn chain_cases() {
+        const A: ConstList<i32> = const_list![1, 2];
+        const B: ConstList<i32> = const_list![3, 4, 5];
+        let empty = ConstList::new();
+
+        let chain = A.chain(&B);
+        assert_eq!(chain.size_hint(), (5, Some(5)));
+        assert_eq!(chain.len(), A.len() + B.len());
+        assert!(chain.eq(&[1, 2, 3, 4, 5]));
+        assert!(A.chain(&B).rev().eq(&[5, 4, 3, 2, 1]));
+
+        let mut both_ends = A.chain(&B);
+        assert_eq!(both_ends.next_back(), Some(&5));
+        assert_eq!(both_ends.next(), Some(&1));
+        assert_eq!(both_ends.next_back(), Some(&4));
+        assert_eq!(both_ends.next_back(), Some(&3));
+        assert_eq!(both_ends.len(), 1);
+        assert_eq!(both_ends.next_back(), Some(&2));
+        assert_eq!(both_ends.next(), None);
+
+        assert!(empty.chain(&B).eq(&[3, 4, 5]));
+        assert!(A.chain(&empty).eq(&[1, 2]));
+        assert!(A.chain(&empty).rev().eq(&[2, 1]));
+        assert_eq!(empty.chain(&empty).len(), 0);
+    }
+
+    #[test]
+    fn iterator_nth_and_count() {
+        const LIST: ConstList<i32> = const_list![1, 2, 3, 4, 5];
+        let mut iter = LIST.iter();
+        assert_eq!(iter.nth(1), Some(&2));
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.count(), 3);
+        assert_eq!(iter.next(), Some(&3));
+
+        let mut past_end = LIST.iter();
+        assert_eq!(past_end.nth(5), None);
+        assert_eq!(past_end.next(), None);
+        assert_eq!(past_end.next_back(), None);
+        assert_eq!(past_end.len(), 0);
+        assert_eq!(past_end.count(), 0);
+
+        let mut shrunk = LIST.iter();
+        assert_eq!(shrunk.next_back(), Some(&5));
+        assert_eq!(shrunk.next_back(), Some(&4));
+        assert_eq!(shrunk.nth(3), None);
+        assert_eq!(shrunk.next(), None);
+
+        let mut shrunk = LIST.iter();
+        assert_eq!(shrunk.next_back(), Some(&5));
+        assert_eq!(shrunk.nth(2), Some(&3));
+        assert_eq!(shrunk.len(), 1);
+        assert_eq!(shrunk.next_back(), Some(&4));
+        assert_eq!(shrunk.next(), None);
+    }
+
+    #[test]
+    fn chunks_cases() {
+        use std::vec::Vec;
+
+        const LIST: ConstList<i32> = const_list![1, 2, 3, 4, 5];
+        let chunks = LIST.chunks::<2>();
+        assert_eq!(chunks.len(), LIST.len().div_ceil(2));
+        assert_eq!(
+            chunks.collect::<Vec<_>>(),
+            [([1, 2], 2), ([3, 4], 2), ([5, 0], 1)]
+        );
+        assert_eq!(LIST.chunks::<5>().len(), 1);
+        assert_eq!(LIST.chunks::<5>().next(), Some(([1, 2, 3, 4, 5], 5)));
+        assert_eq!(LIST.chunks::<8>().len(), 1);
+        assert_eq!(
+            LIST.chunks::<8>().next(),
+            Some(([1, 2, 3, 4, 5, 0, 0, 0], 5))
+        );
+
+        let mut chunks = LIST.chunks::<3>();
+        assert_eq!(chunks.next(), Some(([1, 2, 3], 3)));
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks.next(), Some(([4, 5, 0], 2)));
+        assert_eq!(chunks.len(), 0);
+        assert_eq!(chunks.next(), None);
+
+        let empty = ConstList::<i32>::new();
+        let mut empty = empty.chunks::<2>();
+        assert_eq!(empty.len(), 0);
+        assert_eq!(empty.next(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn chunks_zero_size() {
+        const LIST: ConstList<i32> = const_list![1, 2];
+        let _ = LIST.chunks::<0>();
+    }
+
+    #[test]
+    fn split_on_segments() {
+        use std::vec::Vec;
+
+        fn check(list: &ConstList<i32>, expected: &[&[i32]]) {
+            let segments = list
+                .split_on(|x| *x == 0)
+                .map(|segment| segment.copied().collect::<Vec<_>>())
+                .collect::<Vec<_>>();
+            assert_eq!(segments, expected);
+        }
+
+        check(&ConstList::new(), &[&[]]);
+        check(&const_list![1, 2], &[&[1, 2]]);
+        check(&const_list![0], &[&[], &[]]);
+        check(&const_list![0, 0], &[&[], &[], &[]]);
+        check(&const_list![1, 2, 0, 3, 0], &[&[1, 2], &[3], &[]]);
+        check(&const_list![0, 0, 5], &[&[], &[], &[5]]);
+        check(&const_list![0, 4, 0, 0, 6, 7], &[&[], &[4], &[], &[6, 7]]);
+    }
+
+    #[test]
+    fn const_list_from_matches_array() {
+        const fn matches(list: &ConstList<i32>, array: &[i32]) -> bool {
+            if list.len() != array.len() {
+                return false;
+            }
+            let mut index = 0;
+            while index < array.len() {
+                match list.get(index) {
+                    Some(value) if *value == array[index] => index += 1,
+                    _ => return false,
+                }
+            }
+            true
+        }
+
+        const ONE: [i32; 1] = [7];
+        const FULL: [i32; 32] = {
+            let mut array = [0; 32];
+            let mut index = 0;
+            while index < array.len() {
+                array[index] = (index * index) as i32;
+                index += 1;
+            }
+            array
+        };
+        const ONE_LIST: ConstList<i32> = const_list_from!(ONE);
+        const FULL_LIST: ConstList<i32> = const_list_from!(FULL);
+
+        const _: () = assert!(matches(&ONE_LIST, &ONE));
+        const _: () = assert!(matches(&FULL_LIST, &FULL));
+        assert!(!matches(&FULL_LIST, &ONE));
+        assert!(!matches(&ONE_LIST.push(7), &ONE));
+        assert_eq!(ONE_LIST, ONE);
+        assert_eq!(FULL_LIST, FULL);
     }
 }